@@ -0,0 +1,188 @@
+use std::{fmt, fs, iter};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+use crate::error::ParseError;
+use crate::solution::Part;
+
+/// A median that grows by less than this is noise rather than a regression, whatever the percentage.
+pub const MIN_REGRESSION : Duration = Duration::from_micros(100);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase { Parse, Part(Part) }
+
+impl Phase {
+    /// The key of the phase in a baseline file.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part1",
+            Phase::Part(Part::Two) => "part2",
+        }
+    }
+
+    pub fn from_key(key : &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part(Part::One)),
+            "part2" => Some(Phase::Part(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs : usize,
+    pub min : Duration,
+    pub median : Duration,
+    pub p95 : Duration,
+}
+
+impl Stats {
+    /// Summarizes at least one sample.
+    pub fn new(mut samples : Vec<Duration>) -> Self {
+        samples.sort();
+        let at = |q : f64| samples[((samples.len() - 1) as f64 * q).round() as usize];
+        Stats { runs: samples.len(), min: samples[0], median: at(0.5), p95: at(0.95) }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day : u8,
+    pub phase : Phase,
+    pub stats : Stats,
+}
+
+impl Measurement {
+    /// Relative change of the median against the same day and phase in `baseline`.
+    pub fn change(&self, baseline : &[Measurement]) -> Option<(Duration, f64)> {
+        let base = baseline.iter().find(|b| b.day == self.day && b.phase == self.phase)?;
+        let change = self.stats.median.as_secs_f64() / base.stats.median.as_secs_f64().max(1e-9) - 1.0;
+        Some((base.stats.median, change))
+    }
+
+    /// Whether the median grew by more than `threshold` (0.1 being 10%) compared to `baseline`.
+    pub fn regressed(&self, baseline : &[Measurement], threshold : f64) -> bool {
+        match self.change(baseline) {
+            Some((base, change)) => change > threshold && self.stats.median > base + MIN_REGRESSION,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {:02} {:<7} runs {:<3} min {:>10.2?} median {:>10.2?} p95 {:>10.2?}",
+               self.day, self.phase.to_string(), self.stats.runs, self.stats.min, self.stats.median, self.stats.p95)
+    }
+}
+
+/// Solves both parts of `day` `runs` times and collects timings per phase.
+pub fn bench(day : &Day, input : &str, runs : usize) -> Result<Vec<Measurement>, ParseError> {
+    let mut samples : Vec<(Phase, Vec<Duration>)> = Vec::new();
+
+    for _ in 0..runs.max(1) {
+        let report = (day.solve)(input, None)?;
+        let times = iter::once((Phase::Parse, report.parse))
+            .chain(report.parts.iter().map(|s| (Phase::Part(s.part), s.time)));
+        for (phase, time) in times {
+            match samples.iter_mut().find(|(p, _)| *p == phase) {
+                Some((_, phase_samples)) => phase_samples.push(time),
+                None => samples.push((phase, vec![time])),
+            }
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(phase, times)| Measurement { day: day.number, phase, stats: Stats::new(times) })
+        .collect())
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    day : u8,
+    phase : String,
+    runs : usize,
+    min_ns : u64,
+    median_ns : u64,
+    p95_ns : u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    bench : Vec<BaselineEntry>,
+}
+
+pub fn save(path : &str, measurements : &[Measurement]) -> Result<(), String> {
+    let file = BaselineFile {
+        bench: measurements.iter().map(|m| BaselineEntry {
+            day: m.day,
+            phase: m.phase.key().to_string(),
+            runs: m.stats.runs,
+            min_ns: m.stats.min.as_nanos() as u64,
+            median_ns: m.stats.median.as_nanos() as u64,
+            p95_ns: m.stats.p95.as_nanos() as u64,
+        }).collect(),
+    };
+    let text = toml::to_string(&file).map_err(|e| e.to_string())?;
+    fs::write(path, text).map_err(|e| format!("couldn't write {}: {}", path, e))
+}
+
+pub fn load(path : &str) -> Result<Vec<Measurement>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {}", path, e))?;
+    let file : BaselineFile = toml::from_str(&text).map_err(|e| format!("{}: {}", path, e))?;
+    file.bench
+        .into_iter()
+        .map(|e| Ok(Measurement {
+            day: e.day,
+            phase: Phase::from_key(&e.phase).ok_or(format!("{}: unknown phase '{}'", path, e.phase))?,
+            stats: Stats {
+                runs: e.runs,
+                min: Duration::from_nanos(e.min_ns),
+                median: Duration::from_nanos(e.median_ns),
+                p95: Duration::from_nanos(e.p95_ns),
+            },
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{Measurement, Phase, Stats};
+    use crate::solution::Part;
+
+    fn ms(ms : u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new((1..=20).rev().map(ms).collect());
+        assert_eq!(stats, Stats { runs: 20, min: ms(1), median: ms(11), p95: ms(19) });
+        assert_eq!(Stats::new(vec![ms(3)]).p95, ms(3));
+    }
+
+    #[test]
+    fn regression_against_baseline() {
+        let measure = |median| Measurement { day: 15, phase: Phase::Part(Part::Two), stats: Stats { runs: 1, min: ms(median), median: ms(median), p95: ms(median) } };
+        let baseline = vec![measure(100)];
+        assert!(!measure(105).regressed(&baseline, 0.1));
+        assert!(measure(120).regressed(&baseline, 0.1));
+        assert!(!Measurement { day: 14, ..measure(120) }.regressed(&baseline, 0.1));
+    }
+}
@@ -0,0 +1,182 @@
+use std::{fmt, fs, panic};
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::days;
+use crate::solution::{Answer, Part};
+use crate::tools::Source;
+
+/// The known answers for one day and input, as listed in `answers.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Expected {
+    pub day : u8,
+    pub input : String,
+    pub part1 : Option<Answer>,
+    pub part2 : Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part : Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    answers : Vec<Expected>,
+}
+
+fn read_toml<T : DeserializeOwned>(path : &str) -> Result<T, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {}", path, e))?;
+    toml::from_str(&text).map_err(|e| format!("{}: {}", path, e))
+}
+
+pub fn load(path : &str) -> Result<Vec<Expected>, String> {
+    let answers = read_toml::<AnswersFile>(path)?.answers;
+    match answers.iter().find(|a| a.part1.is_none() && a.part2.is_none()) {
+        Some(a) => Err(format!("{}: expected part1 or part2 for day {} and {}", path, a.day, a.input)),
+        None => Ok(answers),
+    }
+}
+
+#[derive(Deserialize)]
+struct Sidecar {
+    part1 : Option<Answer>,
+    part2 : Option<Answer>,
+}
+
+/// Finds every `dayNN_example*` input in `dir`, each paired with the answers in its
+/// `dayNN_example*.expected` sidecar.
+pub fn examples(dir : &str) -> Result<Vec<Expected>, String> {
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir).map_err(|e| format!("couldn't read {}: {}", dir, e))? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = match path.file_name().and_then(|n| n.to_str()) {
+            Some(name) if !name.ends_with(".expected") => name,
+            _ => continue,
+        };
+        let day = match (name.get(0..3), name.get(3..5), name.get(5..)) {
+            (Some("day"), Some(day), Some(rest)) if rest.starts_with("_example") => day.parse().ok(),
+            _ => None,
+        };
+        if let Some(day) = day {
+            let input = path.to_string_lossy().into_owned();
+            let sidecar_path = format!("{}.expected", input);
+            let sidecar : Sidecar = read_toml(&sidecar_path)?;
+            if sidecar.part1.is_none() && sidecar.part2.is_none() {
+                return Err(format!("{}: expected part1 or part2", sidecar_path));
+            }
+            examples.push(Expected { day, input, part1: sidecar.part1, part2: sidecar.part2 });
+        }
+    }
+
+    examples.sort_by(|a, b| a.input.cmp(&b.input));
+    Ok(examples)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { expected : Answer, actual : Answer },
+    Fail(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day : u8,
+    pub part : Option<Part>,
+    pub input : String,
+    pub verdict : Verdict,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {:02}", self.day)?;
+        if let Some(part) = self.part {
+            write!(f, " part {}", part)?;
+        }
+        write!(f, " ({}): ", self.input)?;
+        match &self.verdict {
+            Verdict::Pass => write!(f, "ok"),
+            Verdict::Mismatch { expected, actual } => write!(f, "MISMATCH expected {}, got {}", expected, actual),
+            Verdict::Fail(reason) => write!(f, "FAIL {}", reason),
+        }
+    }
+}
+
+/// Solves the parts listed in `expected` for its input and compares the answers.
+/// A part without an expected answer is not solved when `part` leaves the choice open.
+pub fn check(expected : &Expected, part : Option<Part>) -> Vec<Check> {
+    let part = part.or(match (&expected.part1, &expected.part2) {
+        (Some(_), None) => Some(Part::One),
+        (None, Some(_)) => Some(Part::Two),
+        _ => None,
+    });
+
+    let fail = |reason : String| vec![Check { day: expected.day, part, input: expected.input.clone(), verdict: Verdict::Fail(reason) }];
+
+    let day = match days::find(expected.day) {
+        Some(day) => day,
+        None => return fail(format!("day {} is not implemented", expected.day)),
+    };
+
+    let input = match Source::from_arg(&expected.input).read() {
+        Ok(input) => input,
+        Err(e) => return fail(format!("couldn't read input: {}", e)),
+    };
+
+    let report = match panic::catch_unwind(|| (day.solve)(&input, part)) {
+        Ok(Ok(report)) => report,
+        Ok(Err(e)) => return fail(e.in_file(&expected.input).to_string()),
+        Err(_) => return fail("solver panicked".to_string()),
+    };
+
+    [Part::One, Part::Two]
+        .iter()
+        .filter(|&&p| part.is_none_or(|part| part == p))
+        .filter_map(|&p| {
+            let expected_answer = expected.part(p)?;
+            let verdict = match report.parts.iter().find(|solved| solved.part == p).map(|solved| &solved.answer) {
+                Some(Ok(answer)) if answer == expected_answer => Verdict::Pass,
+                Some(Ok(answer)) => Verdict::Mismatch { expected: expected_answer.clone(), actual: answer.clone() },
+                Some(Err(e)) => Verdict::Fail(e.clone().in_file(&expected.input).to_string()),
+                None => Verdict::Fail(format!("no answer for part {}", p)),
+            };
+            Some(Check { day: expected.day, part: Some(p), input: expected.input.clone(), verdict })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Expected, Verdict};
+    use crate::solution::{Answer, Part};
+
+    fn day13_example(part1 : i64) -> Expected {
+        Expected { day: 13, input: "input/day13_example".to_string(), part1: Some(Answer::Number(part1)), part2: None }
+    }
+
+    #[test]
+    fn verdicts() {
+        let checks = check(&day13_example(295), None);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].part, Some(Part::One));
+        assert_eq!(checks[0].verdict, Verdict::Pass);
+
+        let checks = check(&day13_example(1), None);
+        assert_eq!(checks[0].verdict, Verdict::Mismatch { expected: Answer::Number(1), actual: Answer::Number(295) });
+
+        let checks = check(&Expected { input: "input/day00".to_string(), ..day13_example(295) }, None);
+        assert!(matches!(checks[0].verdict, Verdict::Fail(_)));
+
+        let day25 = Expected { day: 25, input: "input/day25_example".to_string(), part1: None, part2: Some(Answer::Number(5)) };
+        let checks = check(&day25, None);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].verdict, Verdict::Fail("no answer for part 2".to_string()));
+    }
+}
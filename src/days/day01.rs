@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::error::{parse_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub const TARGET : i64 = 2020;
+
+/// Every set of `k` distinct entries summing to `target`, as their indices in ascending order.
+///
+/// Fixes the smallest entry of a set and looks for the other `k - 1` among the larger ones, down to
+/// pairs, which are found with a single pass over a map of the entries seen so far. That takes
+/// about n^(k-1) steps rather than n^k, plus one per combination found.
+pub fn k_sum(nums : &[i64], k : usize, target : i64) -> Vec<Vec<usize>> {
+    let mut order : Vec<usize> = (0..nums.len()).collect();
+    order.sort_by_key(|&i| nums[i]);
+
+    let mut found = Vec::new();
+    sums(nums, &order, k, target as i128, &mut Vec::new(), &mut found);
+
+    for combination in &mut found {
+        combination.sort_unstable();
+    }
+    found.sort_unstable();
+    found
+}
+
+/// Adds the sets of `k` entries of `order` summing to `target`, together with `chosen`, to `found`.
+///
+/// What is left of the target once some entries are chosen need not fit in an i64, so it's kept as an i128.
+fn sums(nums : &[i64], order : &[usize], k : usize, target : i128, chosen : &mut Vec<usize>, found : &mut Vec<Vec<usize>>) {
+    match k {
+        0 => if target == 0 {
+            found.push(chosen.clone());
+        },
+        1 => for &i in order.iter().filter(|&&i| nums[i] as i128 == target) {
+            found.push([&chosen[..], &[i]].concat());
+        },
+        2 => {
+            let mut seen : HashMap<i64, Vec<usize>> = HashMap::new();
+            for &j in order {
+                // No entry can make up a difference that doesn't fit in an i64.
+                if let Some(matches) = i64::try_from(target - nums[j] as i128).ok().and_then(|rest| seen.get(&rest)) {
+                    for &i in matches {
+                        found.push([&chosen[..], &[i, j]].concat());
+                    }
+                }
+                seen.entry(nums[j]).or_default().push(j);
+            }
+        },
+        _ => for (pos, &i) in order.iter().enumerate() {
+            // The entries are sorted, so once the smallest k are too large nothing after fits either.
+            // A product too large for an i128 is certainly above the target.
+            if order.len() - pos < k || nums[i] >= 0 && (nums[i] as i128).checked_mul(k as i128).is_none_or(|least| least > target) {
+                break;
+            }
+            chosen.push(i);
+            sums(nums, &order[pos + 1..], k - 1, target - nums[i] as i128, chosen, found);
+            chosen.pop();
+        },
+    }
+}
+
+/// The product of the first `k` entries summing to 2020.
+fn product(nums : &[i64], k : usize) -> Result<i64, ParseError> {
+    let combination = k_sum(nums, k, TARGET).into_iter().next()
+        .ok_or_else(|| ParseError::new(1, 1, "", format!("no {} entries sum to {}", k, TARGET)))?;
+    combination.iter()
+        .try_fold(1i64, |product, &i| product.checked_mul(nums[i]))
+        .ok_or_else(|| ParseError::new(combination[0] + 1, 1, "", format!("the product of the {} entries summing to {} is too large", k, TARGET)))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(nums : &Self::Input) -> Result<Answer, ParseError> {
+        product(nums, 2).map(Answer::from)
+    }
+
+    fn part2(nums : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(product(nums, 3).map(Answer::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{k_sum, Day01, TARGET};
+    use crate::solution::Solution;
+
+    const EXAMPLE : &[i64] = &[1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn sums() {
+        assert_eq!(k_sum(EXAMPLE, 2, TARGET), vec![vec![0, 3]]);
+        assert_eq!(k_sum(EXAMPLE, 3, TARGET), vec![vec![1, 2, 4]]);
+        assert_eq!(k_sum(EXAMPLE, 1, 366), vec![vec![2]]);
+        assert!(k_sum(EXAMPLE, 6, TARGET).is_empty());
+    }
+
+    #[test]
+    fn distinct_indices() {
+        assert!(k_sum(&[1010, 1, 2], 2, 2020).is_empty());
+        assert_eq!(k_sum(&[1010, 5, 1010, 1010], 2, 2020), vec![vec![0, 2], vec![0, 3], vec![2, 3]]);
+        assert_eq!(k_sum(&[3, -1, 2, 0, 1], 3, 2), vec![vec![0, 1, 3], vec![1, 2, 4]]);
+        assert_eq!(k_sum(&[1, 2, 3, 4, 5], 4, 10), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(k_sum(&[1, 2], 0, 0), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn extreme_targets() {
+        let nums = &[i64::MAX, i64::MIN, 1, -1, 0];
+        assert_eq!(k_sum(nums, 2, i64::MIN), vec![vec![1, 4]]);
+        assert_eq!(k_sum(nums, 2, i64::MAX), vec![vec![0, 4]]);
+        assert_eq!(k_sum(nums, 3, i64::MIN), vec![vec![1, 2, 3]]);
+        assert_eq!(k_sum(nums, 3, i64::MAX), vec![vec![0, 2, 3]]);
+        assert_eq!(k_sum(nums, 2, -1), vec![vec![0, 1], vec![3, 4]]);
+    }
+
+    #[test]
+    fn no_answer() {
+        assert_eq!(Day01::part1(&vec![1, 2]).unwrap_err().to_string(), "1:1: no 2 entries sum to 2020");
+        let nums = vec![1 << 62, -(1 << 62), 2020, 4];
+        assert_eq!(Day01::part2(&nums).unwrap().unwrap_err().to_string(), "1:1: the product of the 3 entries summing to 2020 is too large");
+    }
+}
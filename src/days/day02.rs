@@ -0,0 +1,211 @@
+use serde::Serialize;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// A line of the password database: the policy's two numbers and letter, and the password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PasswordEntry {
+    pub lo : usize,
+    pub hi : usize,
+    pub ch : char,
+    pub password : String,
+}
+
+/// A way of reading the numbers and letter of an entry as a rule the password has to follow.
+pub trait PasswordPolicy {
+    fn name(&self) -> &'static str;
+
+    /// Why the password breaks the rule, if it does.
+    fn check(&self, entry : &PasswordEntry) -> Result<(), String>;
+
+    fn allows(&self, entry : &PasswordEntry) -> bool {
+        self.check(entry).is_ok()
+    }
+}
+
+/// The letter appears at least `lo` and at most `hi` times, the sled rental place's policy.
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn name(&self) -> &'static str {
+        "count"
+    }
+
+    fn check(&self, entry : &PasswordEntry) -> Result<(), String> {
+        let count = entry.password.chars().filter(|&c| c == entry.ch).count();
+        if (entry.lo..=entry.hi).contains(&count) {
+            Ok(())
+        } else {
+            Err(format!("'{}' occurs {} times, allowed {}-{}", entry.ch, count, entry.lo, entry.hi))
+        }
+    }
+}
+
+/// The letter is at exactly one of the positions `lo` and `hi`, counting from 1, the toboggan
+/// corporate policy.
+pub struct Positional;
+
+impl PasswordPolicy for Positional {
+    fn name(&self) -> &'static str {
+        "positional"
+    }
+
+    fn check(&self, entry : &PasswordEntry) -> Result<(), String> {
+        let at = |position : usize| position.checked_sub(1).and_then(|i| entry.password.chars().nth(i)) == Some(entry.ch);
+        match (at(entry.lo), at(entry.hi)) {
+            (true, true) => Err(format!("'{}' is at both positions {} and {}", entry.ch, entry.lo, entry.hi)),
+            (false, false) => Err(format!("'{}' is at neither position {} nor {}", entry.ch, entry.lo, entry.hi)),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub const POLICIES : &[&dyn PasswordPolicy] = &[&CountRange, &Positional];
+
+pub fn count_valid(entries : &[PasswordEntry], policy : &dyn PasswordPolicy) -> usize {
+    entries.iter().filter(|e| policy.allows(e)).count()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verdict {
+    pub policy : &'static str,
+    pub valid : bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason : Option<String>,
+}
+
+/// An entry of the database and how it fares under each policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryReport {
+    pub line : usize,
+    #[serde(flatten)]
+    pub entry : PasswordEntry,
+    pub verdicts : Vec<Verdict>,
+}
+
+impl EntryReport {
+    pub fn compliant(&self) -> bool {
+        self.verdicts.iter().all(|v| v.valid)
+    }
+}
+
+pub fn report(entries : &[PasswordEntry], policies : &[&dyn PasswordPolicy]) -> Vec<EntryReport> {
+    entries.iter()
+        .enumerate()
+        .map(|(i, entry)| EntryReport {
+            line: i + 1,
+            entry: entry.clone(),
+            verdicts: policies.iter()
+                .map(|p| {
+                    let reason = p.check(entry).err();
+                    Verdict { policy: p.name(), valid: reason.is_none(), reason }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn csv_field(field : &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One row per entry and policy, with a header row.
+pub fn csv(reports : &[EntryReport]) -> String {
+    let mut csv = String::from("line,lo,hi,ch,password,policy,valid,reason\n");
+    for r in reports {
+        for v in &r.verdicts {
+            let row = [
+                r.line.to_string(), r.entry.lo.to_string(), r.entry.hi.to_string(), r.entry.ch.to_string(),
+                r.entry.password.clone(), v.policy.to_string(), v.valid.to_string(), v.reason.clone().unwrap_or_default(),
+            ];
+            let row : Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+pub fn json(reports : &[EntryReport]) -> String {
+    serde_json::to_string_pretty(reports).expect("Reports always serialize")
+}
+
+pub fn read(input : &str) -> Result<Vec<PasswordEntry>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let parts : Vec<&str> = line.split([' ', ':', '-']).collect();
+            if let [lo, hi, ch, _, password] = &parts[..] {
+                let mut chars = ch.chars();
+                let ch = match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => return Err(ParseError::at(i + 1, line, ch, "expected a single character")),
+                };
+                Ok(PasswordEntry { lo: parse(i + 1, line, lo)?, hi: parse(i + 1, line, hi)?, ch, password: password.to_string() })
+            } else {
+                Err(ParseError::at(i + 1, line, line, "expected 'lo-hi char: password'"))
+            }
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<PasswordEntry>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(entries : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(count_valid(entries, &CountRange).into())
+    }
+
+    fn part2(entries : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(count_valid(entries, &Positional).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_valid, csv, json, read, report, CountRange, PasswordEntry, PasswordPolicy, Positional, POLICIES};
+
+    #[test]
+    fn policies() {
+        let entries = read("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        assert_eq!(entries[1], PasswordEntry { lo: 1, hi: 3, ch: 'b', password: "cdefg".to_string() });
+        assert_eq!(count_valid(&entries, &CountRange), 2);
+        assert_eq!(count_valid(&entries, &Positional), 1);
+        assert!(!Positional.allows(&PasswordEntry { lo: 0, hi: 4, ch: 'a', password: "abc".to_string() }));
+    }
+
+    #[test]
+    fn reports_reasons() {
+        let entries = read("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-2 ,: ,,\n").unwrap();
+        let reports = report(&entries, POLICIES);
+        assert!(reports[0].compliant());
+        assert_eq!(reports[1].verdicts[0].reason.as_deref(), Some("'b' occurs 0 times, allowed 1-3"));
+        assert_eq!(reports[2].verdicts[1].reason.as_deref(), Some("'c' is at both positions 2 and 9"));
+        assert_eq!(reports[2].line, 3);
+
+        let csv = csv(&reports);
+        let rows : Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 1 + 4 * 2);
+        assert_eq!(rows[3], "2,1,3,b,cdefg,count,false,\"'b' occurs 0 times, allowed 1-3\"");
+        assert_eq!(rows[4], "2,1,3,b,cdefg,positional,false,'b' is at neither position 1 nor 3");
+        assert_eq!(rows[7], "4,1,2,\",\",\",,\",count,true,");
+
+        let value : serde_json::Value = serde_json::from_str(&json(&reports)).unwrap();
+        assert_eq!(value[0]["ch"], "a");
+        assert_eq!(value[0]["verdicts"][0]["valid"], true);
+        assert!(value[0]["verdicts"][0].get("reason").is_none());
+        assert_eq!(value[1]["verdicts"][1]["policy"], "positional");
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+/// A grid of trees that repeats itself endlessly to the right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatingGrid {
+    pub width : usize,
+    pub rows : Vec<Vec<bool>>,
+}
+
+impl RepeatingGrid {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether there is a tree at `x`, `y`; `None` below the bottom row.
+    pub fn tree(&self, x : usize, y : usize) -> Option<bool> {
+        self.rows.get(y).map(|row| row[x % self.width])
+    }
+
+    /// Counts the trees hit going `right`, `down` at a time from the top left to the bottom.
+    pub fn trees_on_slope(&self, (right, down) : (usize, usize)) -> usize {
+        (0..self.height())
+            .step_by(down.max(1))
+            .enumerate()
+            .filter(|&(step, y)| self.tree(step * right, y) == Some(true))
+            .count()
+    }
+
+    /// Multiplies the trees hit on each of the slopes.
+    pub fn trees_on_slopes(&self, slopes : &[(usize, usize)]) -> usize {
+        slopes.iter().map(|&slope| self.trees_on_slope(slope)).product()
+    }
+}
+
+pub const SLOPE : (usize, usize) = (3, 1);
+pub const SLOPES : &[(usize, usize)] = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub fn read(input : &str) -> Result<RepeatingGrid, ParseError> {
+    let mut rows : Vec<Vec<bool>> = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        let row = line.char_indices()
+            .map(|(x, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::at(y + 1, line, &line[x..x + c.len_utf8()], "expected '#' or '.'")),
+            })
+            .collect::<Result<Vec<bool>, ParseError>>()?;
+        if row.is_empty() || rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseError::at(y + 1, line, line, format!("expected a row of {} squares", rows.first().map_or(1, Vec::len))));
+        }
+        rows.push(row);
+    }
+
+    let width = rows.first().map_or(0, Vec::len);
+    Ok(RepeatingGrid { width, rows })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = RepeatingGrid;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(grid : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(grid.trees_on_slope(SLOPE).into())
+    }
+
+    fn part2(grid : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(grid.trees_on_slopes(SLOPES).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, SLOPES};
+
+    #[test]
+    fn trees_on_example_slopes() {
+        let grid = read("..##.......\n#...#...#..\n.#....#..#.\n..#.#...#.#\n.#...##..#.\n..#.##.....\n.#.#.#....#\n.#........#\n#.##...#...\n#...##....#\n.#..#...#.#\n").unwrap();
+        let trees : Vec<usize> = SLOPES.iter().map(|&s| grid.trees_on_slope(s)).collect();
+        assert_eq!(trees, vec![2, 7, 3, 4, 2]);
+        assert_eq!(grid.trees_on_slopes(SLOPES), 336);
+        assert_eq!(grid.tree(11, 0), Some(false));
+        assert_eq!(grid.tree(13, 0), Some(true));
+        assert_eq!(grid.tree(0, 11), None);
+        assert!(read("..#\n.#\n").is_err());
+    }
+}
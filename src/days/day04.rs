@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Details, Solution};
+use crate::tools::groups;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passport {
+    pub line : usize,
+    pub fields : Vec<(String, String)>,
+}
+
+impl Passport {
+    pub fn get(&self, key : &str) -> Option<&str> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+}
+
+/// What a field's value has to look like.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// Exactly `digits` digits, read as a number within `min..=max`.
+    Number { digits : usize, min : u64, max : u64 },
+    /// A number directly followed by one of the units, within that unit's range.
+    Measure(&'static [(&'static str, u64, u64)]),
+    /// `#` followed by six lowercase hexadecimal digits.
+    Colour,
+    OneOf(&'static [&'static str]),
+}
+
+impl Rule {
+    pub fn check(&self, value : &str) -> bool {
+        let number = |digits : &str| -> Option<u64> {
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok()
+        };
+        match self {
+            Rule::Number { digits, min, max } => value.len() == *digits
+                && number(value).is_some_and(|n| (*min..=*max).contains(&n)),
+            Rule::Measure(units) => units.iter().any(|(unit, min, max)| {
+                value.strip_suffix(unit)
+                    .and_then(number)
+                    .is_some_and(|n| (*min..=*max).contains(&n))
+            }),
+            Rule::Colour => value.strip_prefix('#')
+                .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))),
+            Rule::OneOf(values) => values.contains(&value),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Number { digits, min, max } => write!(f, "{} digits from {} to {}", digits, min, max),
+            Rule::Measure(units) => {
+                let units : Vec<String> = units.iter().map(|(unit, min, max)| format!("{}-{}{}", min, max, unit)).collect();
+                write!(f, "{}", units.join(" or "))
+            },
+            Rule::Colour => write!(f, "# and six hexadecimal digits"),
+            Rule::OneOf(values) => write!(f, "one of {}", values.join(", ")),
+        }
+    }
+}
+
+pub struct FieldRule {
+    pub key : &'static str,
+    pub rule : Rule,
+}
+
+/// The required fields; `cid` is optional and not listed.
+pub const RULES : &[FieldRule] = &[
+    FieldRule { key: "byr", rule: Rule::Number { digits: 4, min: 1920, max: 2002 } },
+    FieldRule { key: "iyr", rule: Rule::Number { digits: 4, min: 2010, max: 2020 } },
+    FieldRule { key: "eyr", rule: Rule::Number { digits: 4, min: 2020, max: 2030 } },
+    FieldRule { key: "hgt", rule: Rule::Measure(&[("cm", 150, 193), ("in", 59, 76)]) },
+    FieldRule { key: "hcl", rule: Rule::Colour },
+    FieldRule { key: "ecl", rule: Rule::OneOf(&["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]) },
+    FieldRule { key: "pid", rule: Rule::Number { digits: 9, min: 0, max: 999_999_999 } },
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Failure {
+    Missing(&'static str),
+    Invalid { key : &'static str, value : String, rule : &'static Rule },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Missing(key) => write!(f, "{} is missing", key),
+            Failure::Invalid { key, value, rule } => write!(f, "{} '{}' is not {}", key, value, rule),
+        }
+    }
+}
+
+/// Every rule the passport breaks, an empty list meaning it is valid.
+pub fn validate(passport : &Passport, rules : &'static [FieldRule]) -> Vec<Failure> {
+    rules.iter()
+        .filter_map(|r| match passport.get(r.key) {
+            None => Some(Failure::Missing(r.key)),
+            Some(value) if !r.rule.check(value) => Some(Failure::Invalid { key: r.key, value: value.to_string(), rule: &r.rule }),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+pub fn read(input : &str) -> Result<Vec<Passport>, ParseError> {
+    groups(input)
+        .into_iter()
+        .map(|group| {
+            let mut fields = Vec::new();
+            for &(line_no, line) in &group {
+                for token in line.split_whitespace() {
+                    let (key, value) = token.split_once(':')
+                        .ok_or_else(|| ParseError::at(line_no, line, token, "expected 'key:value'"))?;
+                    fields.push((key.to_string(), value.to_string()));
+                }
+            }
+            Ok(Passport { line: group[0].0, fields })
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(passports : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(passports.iter()
+            .filter(|p| validate(p, RULES).iter().all(|f| !matches!(f, Failure::Missing(_))))
+            .count()
+            .into())
+    }
+
+    fn part2(passports : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Self::part2_details(passports).map(|result| result.map(|(answer, _)| answer))
+    }
+
+    fn part2_details(passports : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        let failures : Vec<String> = passports.iter()
+            .filter_map(|p| {
+                let failures = validate(p, RULES);
+                if failures.is_empty() {
+                    return None;
+                }
+                let failures : Vec<String> = failures.iter().map(Failure::to_string).collect();
+                Some(format!("line {}: {}", p.line, failures.join(", ")))
+            })
+            .collect();
+
+        Some(Ok(((passports.len() - failures.len()).into(), vec![
+            ("invalid", failures.len().into()),
+            ("failures", failures.join("; ").into()),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, validate, Failure, RULES};
+
+    #[test]
+    fn rules() {
+        let check = |key : &str, value : &str| RULES.iter().find(|r| r.key == key).unwrap().rule.check(value);
+        assert!(check("byr", "2002"));
+        assert!(!check("byr", "2003"));
+        assert!(check("hgt", "60in"));
+        assert!(check("hgt", "190cm"));
+        assert!(!check("hgt", "190in"));
+        assert!(!check("hgt", "190"));
+        assert!(check("hcl", "#123abc"));
+        assert!(!check("hcl", "#123abz"));
+        assert!(!check("hcl", "123abc"));
+        assert!(check("ecl", "brn"));
+        assert!(!check("ecl", "wat"));
+        assert!(check("pid", "000000001"));
+        assert!(!check("pid", "0123456789"));
+    }
+
+    #[test]
+    fn reports_failed_rules() {
+        let passports = read("hgt:59cm ecl:zzz\neyr:2038 hcl:74454a iyr:2023\npid:3556412378 byr:2007\n\niyr:2019\n").unwrap();
+        let failures = validate(&passports[0], RULES);
+        assert_eq!(failures.len(), 7);
+        assert_eq!(failures[3].to_string(), "hgt '59cm' is not 150-193cm or 59-76in");
+        assert_eq!(validate(&passports[1], RULES)[0], Failure::Missing("byr"));
+        assert_eq!(passports[1].line, 5);
+        assert_eq!(validate(&passports[1], RULES).len(), 6);
+        assert!(read("byr 2002\n").is_err());
+    }
+}
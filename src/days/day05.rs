@@ -0,0 +1,102 @@
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub const ROW_BITS : usize = 7;
+pub const COLUMN_BITS : usize = 3;
+
+/// Reads a boarding pass as the binary seat ID it spells out, `B` and `R` being ones.
+pub fn decode(pass : &str) -> Option<u16> {
+    if pass.len() != ROW_BITS + COLUMN_BITS {
+        return None;
+    }
+    pass.chars().enumerate().try_fold(0, |id, (i, c)| {
+        let bit = match (i < ROW_BITS, c) {
+            (true, 'F') | (false, 'L') => 0,
+            (true, 'B') | (false, 'R') => 1,
+            _ => return None,
+        };
+        Some(id << 1 | bit)
+    })
+}
+
+/// The boarding pass for a seat ID, the reverse of [`decode`].
+pub fn encode(id : u16) -> String {
+    (0..ROW_BITS + COLUMN_BITS).rev()
+        .map(|bit| {
+            let one = id >> bit & 1 == 1;
+            match (bit >= COLUMN_BITS, one) {
+                (true, false) => 'F',
+                (true, true) => 'B',
+                (false, false) => 'L',
+                (false, true) => 'R',
+            }
+        })
+        .collect()
+}
+
+pub fn read(input : &str) -> Result<Vec<u16>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| decode(l.trim()).ok_or_else(|| ParseError::at(i + 1, l, l, "expected 7 of F or B followed by 3 of L or R")))
+        .collect()
+}
+
+/// The only seat missing from the list whose neighbours are both taken.
+pub fn missing_seat(ids : &[u16]) -> Option<u16> {
+    let mut ids = ids.to_vec();
+    ids.sort_unstable();
+    ids.windows(2)
+        .find(|w| w[1] == w[0] + 2)
+        .map(|w| w[0] + 1)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<u16>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(ids : &Self::Input) -> Result<Answer, ParseError> {
+        ids.iter().max().map(|&id| id.into()).ok_or_else(|| ParseError::new(1, 1, "", "expected boarding passes"))
+    }
+
+    fn part2(ids : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(missing_seat(ids).map(Answer::from).ok_or_else(|| ParseError::new(1, 1, "", "found no free seat between two taken ones")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, missing_seat, Day05};
+    use crate::solution::Solution;
+
+    #[test]
+    fn decode_examples() {
+        assert_eq!(decode("FBFBBFFRLR"), Some(357));
+        assert_eq!(decode("BFFFBBFRRR"), Some(567));
+        assert_eq!(decode("FFFBBBFRRR"), Some(119));
+        assert_eq!(decode("BBFFBBFRLL"), Some(820));
+        assert_eq!(decode("BBFFBBFRL"), None);
+        assert_eq!(decode("BBFFBBRRLL"), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(encode(357), "FBFBBFFRLR");
+        for id in 0..1024 {
+            assert_eq!(decode(&encode(id)), Some(id));
+        }
+    }
+
+    #[test]
+    fn finds_missing_seat() {
+        assert_eq!(missing_seat(&[12, 9, 10, 13]), Some(11));
+        assert_eq!(missing_seat(&[9, 10]), None);
+        assert_eq!(Day05::part2(&vec![9, 10]).unwrap().unwrap_err().to_string(), "1:1: found no free seat between two taken ones");
+        assert!(Day05::part1(&Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::tools::groups;
+
+/// The questions one person answered "yes" to, bit 0 being `a`.
+pub type Questions = u32;
+
+pub type Group = Vec<Questions>;
+
+pub fn read(input : &str) -> Result<Vec<Group>, ParseError> {
+    groups(input)
+        .into_iter()
+        .map(|group| group
+            .into_iter()
+            .map(|(line_no, line)| line.char_indices().try_fold(0, |questions : Questions, (i, c)| match c {
+                'a'..='z' => Ok(questions | 1 << (c as u8 - b'a')),
+                _ => Err(ParseError::at(line_no, line, &line[i..i + c.len_utf8()], "expected a question from a to z")),
+            }))
+            .collect())
+        .collect()
+}
+
+/// The questions anyone in the group answered "yes" to.
+pub fn anyone(group : &Group) -> Questions {
+    group.iter().fold(0, |all, &q| all | q)
+}
+
+/// The questions everyone in the group answered "yes" to.
+pub fn everyone(group : &Group) -> Questions {
+    group.iter().fold(!0, |all, &q| all & q)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Group>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(groups : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(groups.iter().map(|g| anyone(g).count_ones()).sum::<u32>().into())
+    }
+
+    fn part2(groups : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(groups.iter().map(|g| everyone(g).count_ones()).sum::<u32>().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{anyone, everyone, read};
+
+    #[test]
+    fn union_and_intersection() {
+        let groups = read("abc\n\na\nb\nc\n\nab\nac\n").unwrap();
+        assert_eq!(groups[1], vec![0b001, 0b010, 0b100]);
+        assert_eq!(anyone(&groups[2]), 0b111);
+        assert_eq!(everyone(&groups[2]), 0b001);
+        assert_eq!(everyone(&groups[1]), 0);
+        assert_eq!(read("ab\naB\n").unwrap_err().column, 2);
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub const TARGET : &str = "shiny gold";
+
+/// Bag colours and, for each, how many bags of which colour it has to contain.
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub colours : Vec<String>,
+    pub contents : Vec<Vec<(usize, u64)>>,
+    index : HashMap<String, usize>,
+}
+
+impl Graph {
+    pub fn find(&self, colour : &str) -> Option<usize> {
+        self.index.get(colour).copied()
+    }
+
+    fn add(&mut self, colour : &str) -> usize {
+        if let Some(bag) = self.find(colour) {
+            return bag;
+        }
+        self.colours.push(colour.to_string());
+        self.contents.push(Vec::new());
+        self.index.insert(colour.to_string(), self.colours.len() - 1);
+        self.colours.len() - 1
+    }
+
+    /// How many colours can eventually contain a `bag`.
+    pub fn containers(&self, bag : usize) -> usize {
+        let mut parents = vec![Vec::new(); self.colours.len()];
+        for (outer, contents) in self.contents.iter().enumerate() {
+            for &(inner, _) in contents {
+                parents[inner].push(outer);
+            }
+        }
+
+        let mut seen = vec![false; self.colours.len()];
+        let mut queue : VecDeque<usize> = parents[bag].iter().copied().collect();
+        while let Some(outer) = queue.pop_front() {
+            if !seen[outer] {
+                seen[outer] = true;
+                queue.extend(&parents[outer]);
+            }
+        }
+        seen.iter().filter(|&&s| s).count()
+    }
+
+    /// How many bags a `bag` holds in total.
+    pub fn inside(&self, bag : usize) -> u64 {
+        fn count(graph : &Graph, bag : usize, memo : &mut Vec<Option<u64>>) -> u64 {
+            if let Some(n) = memo[bag] {
+                return n;
+            }
+            let n = graph.contents[bag].iter().map(|&(inner, n)| n * (1 + count(graph, inner, memo))).sum();
+            memo[bag] = Some(n);
+            n
+        }
+
+        count(self, bag, &mut vec![None; self.colours.len()])
+    }
+
+    /// A chain of bags that ends up containing its first bag, if there is one.
+    pub fn cycle(&self) -> Option<Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark { New, Open, Done }
+
+        fn visit(graph : &Graph, bag : usize, marks : &mut Vec<Mark>, path : &mut Vec<usize>) -> Option<Vec<usize>> {
+            match marks[bag] {
+                Mark::Done => return None,
+                Mark::Open => {
+                    let start = path.iter().position(|&b| b == bag).unwrap();
+                    let mut cycle = path[start..].to_vec();
+                    cycle.push(bag);
+                    return Some(cycle);
+                },
+                Mark::New => {},
+            }
+            marks[bag] = Mark::Open;
+            path.push(bag);
+            for &(inner, _) in &graph.contents[bag] {
+                if let Some(cycle) = visit(graph, inner, marks, path) {
+                    return Some(cycle);
+                }
+            }
+            path.pop();
+            marks[bag] = Mark::Done;
+            None
+        }
+
+        let mut marks = vec![Mark::New; self.colours.len()];
+        (0..self.colours.len()).find_map(|bag| visit(self, bag, &mut marks, &mut Vec::new()))
+    }
+}
+
+/// Splits `"<n> <colour> bag(s)"` into its count and colour.
+fn read_content<'a>(line_no : usize, line : &'a str, content : &'a str) -> Result<(u64, &'a str), ParseError> {
+    let content = content.trim();
+    let colour = content.strip_suffix(" bags").or_else(|| content.strip_suffix(" bag"))
+        .ok_or_else(|| ParseError::at(line_no, line, content, "expected '<n> <colour> bags'"))?;
+    match colour.split_once(' ') {
+        Some((n, colour)) => Ok((parse(line_no, line, n)?, colour)),
+        None => Err(ParseError::at(line_no, line, content, "expected '<n> <colour> bags'")),
+    }
+}
+
+pub fn read(input : &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::default();
+    let mut defined = Vec::new();
+    let mut lines = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (outer, contents) = line.split_once(" bags contain ")
+            .ok_or_else(|| ParseError::at(i + 1, line, line, "expected '<colour> bags contain ...'"))?;
+        let contents = contents.strip_suffix('.')
+            .ok_or_else(|| ParseError::at(i + 1, line, &line[line.len()..], "expected '.'"))?;
+
+        let bag = graph.add(outer);
+        if defined.contains(&bag) {
+            return Err(ParseError::at(i + 1, line, outer, "bag already has a rule"));
+        }
+        defined.push(bag);
+        lines.push((bag, i + 1, line));
+
+        if contents != "no other bags" {
+            for content in contents.split(',') {
+                let (n, inner) = read_content(i + 1, line, content)?;
+                let inner = graph.add(inner);
+                graph.contents[bag].push((inner, n));
+            }
+        }
+    }
+
+    if let Some(cycle) = graph.cycle() {
+        let (_, line_no, line) = lines.iter().find(|(bag, _, _)| *bag == cycle[0]).unwrap();
+        let chain : Vec<&str> = cycle.iter().map(|&bag| graph.colours[bag].as_str()).collect();
+        return Err(ParseError::at(*line_no, line, &line[..graph.colours[cycle[0]].len()],
+                                  format!("bags contain themselves: {}", chain.join(" -> "))));
+    }
+
+    Ok(graph)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Graph;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(graph : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(graph.find(TARGET).map_or(0, |bag| graph.containers(bag)).into())
+    }
+
+    fn part2(graph : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(graph.find(TARGET).map_or(0, |bag| graph.inside(bag)).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, TARGET};
+
+    const EXAMPLE : &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    #[test]
+    fn containment() {
+        let graph = read(EXAMPLE).unwrap();
+        let gold = graph.find(TARGET).unwrap();
+        assert_eq!(graph.containers(gold), 4);
+        assert_eq!(graph.inside(gold), 32);
+        assert_eq!(graph.contents[graph.find("light red").unwrap()].len(), 2);
+    }
+
+    #[test]
+    fn cycles() {
+        let err = read("shiny gold bags contain 1 dark red bag.\ndark red bags contain 2 shiny gold bags.\n").unwrap_err();
+        assert_eq!(err.line, 1);
+        assert_eq!(err.message, "bags contain themselves: shiny gold -> dark red -> shiny gold");
+        assert_eq!(read("dark red bags contain 1 dark red bag.\n").unwrap_err().token, "dark red");
+    }
+}
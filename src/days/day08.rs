@@ -0,0 +1,143 @@
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Details, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Nop,
+    Jmp,
+    Acc,
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub op : Operation,
+    pub val : isize,
+}
+
+#[derive(Default, Debug)]
+pub struct Machine {
+    pub pc : usize,
+    pub r0 : usize,
+}
+
+#[derive(Debug,PartialEq)]
+pub enum Exit { End, Loop }
+
+#[derive(Debug)]
+pub struct ExecResult {
+    pub m : Machine,
+    pub exit : Exit,
+}
+
+pub type Program = Vec<Instruction>;
+
+pub fn execute(p : &Program, m : Option<Machine>) -> ExecResult {
+
+    let mut m = m.unwrap_or_default();
+
+    let p_len = p.len();
+
+    let exit;
+
+    let mut visited : Vec<bool> = vec![false; p_len];
+
+    loop {
+        if m.pc >= p_len {
+            exit = Exit::End;
+            break;
+        }
+
+        if visited[m.pc] {
+            exit = Exit::Loop;
+            break;
+        }
+
+        visited[m.pc] = true;
+
+        let ins = &p[m.pc];
+        match ins.op {
+            Operation::Acc => m.r0 = (m.r0 as isize + ins.val) as usize,
+            Operation::Jmp => {
+                m.pc = (m.pc as isize + ins.val) as usize;
+                continue;
+            },
+            _ => {}
+        }
+        m.pc += 1;
+    }
+
+    ExecResult { m, exit }
+}
+
+pub fn read_program(input : &str) -> Result<Program, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (op_str, val_str) = line.split_once(' ')
+                .ok_or_else(|| ParseError::at(i + 1, line, line, "expected 'op value'"))?;
+            Ok(Instruction {
+                op: match op_str {
+                    "jmp" => Operation::Jmp,
+                    "acc" => Operation::Acc,
+                    "nop" => Operation::Nop,
+                    _ => return Err(ParseError::at(i + 1, line, op_str, "unknown operation")),
+                },
+                val: parse(i + 1, line, val_str.trim())?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Program;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read_program(input)
+    }
+
+    fn part1(program : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(execute(program, None).m.r0.into())
+    }
+
+    fn part2(program : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Self::part2_details(program).map(|result| result.map(|(answer, _)| answer))
+    }
+
+    fn part2_details(program : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        for i in 0..program.len() {
+            let mut copy = program.clone();
+            let ins = & mut copy[i];
+            match ins.op {
+                Operation::Jmp => {
+                    ins.op = Operation::Nop;
+                },
+                Operation::Nop => {
+                    ins.op = Operation::Jmp;
+                },
+                _ => continue
+            }
+            let res = execute(&copy, None);
+            if res.exit == Exit::End {
+                return Some(Ok((res.m.r0.into(), vec![("changed_instruction", i.into())])));
+            }
+        }
+
+        Some(Err(ParseError::new(1, 1, "", "no single jmp or nop change makes the program end")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_program;
+
+    #[test]
+    fn malformed() {
+        let err = read_program("nop +0\nacc x1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 5, "x1"));
+        let err = read_program("jmp +1\nmov +2\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str(), err.message.as_str()), (2, 1, "mov", "unknown operation"));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::error::{parse_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub const PREAMBLE : usize = 25;
+
+pub fn first_invalid(nums : &[u64]) -> Result<u64, ParseError> {
+    for i in PREAMBLE..nums.len() {
+        let num = nums[i];
+        let mut found = false;
+        for j in i-PREAMBLE..i {
+            for k in j..i {
+                if nums[j] + nums[k] == num {
+                    found = true;
+                    break;
+                }
+            }
+        }
+        if !found {
+            return Ok(num);
+        }
+    }
+
+    Err(ParseError::new(1, 1, "", format!("every number after the first {} is a sum of two of the {} before it", PREAMBLE, PREAMBLE)))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(nums : &Self::Input) -> Result<Answer, ParseError> {
+        first_invalid(nums).map(Answer::from)
+    }
+
+    fn part2(nums : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        let num = match first_invalid(nums) {
+            Ok(num) => num,
+            Err(e) => return Some(Err(e)),
+        };
+
+        for i in 0..nums.len().saturating_sub(1) {
+            for j in i+2..nums.len() {
+                let s : u64 = nums[i..j].iter().sum();
+                if s == num {
+                    let range = &nums[i..j];
+                    let sum = range.iter().min().unwrap() + range.iter().max().unwrap();
+                    return Some(Ok(sum.into()));
+                } else if s > num {
+                    break;
+                }
+            }
+        }
+
+        Some(Err(ParseError::new(1, 1, "", format!("no run of at least two numbers sums to {}", num))))
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+use std::cmp::max;
+
+use crate::error::{parse_lines, ParseError};
+use crate::solution::{Answer, Details, Solution};
+
+pub fn read(input : &str) -> Result<Vec<u64>, ParseError> {
+    let mut nums : Vec<u64> = parse_lines(input)?;
+
+    // The chain only works out with one adapter per joltage, and none at the outlet's 0.
+    let mut seen = HashSet::new();
+    for (i, (line, &num)) in input.lines().zip(&nums).enumerate() {
+        if num == 0 {
+            return Err(ParseError::at(i + 1, line, line.trim(), "expected a joltage above the outlet's 0"));
+        }
+        if !seen.insert(num) {
+            return Err(ParseError::at(i + 1, line, line.trim(), "joltage used twice"));
+        }
+    }
+
+    nums.push(0);
+    nums.sort_unstable();
+    nums.push(nums.last().unwrap()+3);
+
+    Ok(nums)
+}
+
+pub fn traverse(from : u64, to : u64, reachable : &HashMap<u64, Vec<u64>>, paths: &mut HashMap<u64, u64>) -> u64 {
+    match paths.get(&to) {
+        Some(&v) => v,
+        None => {
+            let ways = match reachable.get(&to) {
+                Some(reaches) => {
+                    let mut ways = 0;
+                    for &reach in reaches {
+                        if reach == from {
+                            ways += 1;
+                        } else {
+                            ways += traverse(from, reach, reachable, paths);
+                        }
+                    }
+                    ways
+                },
+                None => 0
+            };
+            paths.insert(to, ways);
+            ways
+        },
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u64>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(nums : &Self::Input) -> Result<Answer, ParseError> {
+        Self::part1_details(nums).map(|(answer, _)| answer)
+    }
+
+    fn part1_details(nums : &Self::Input) -> Result<(Answer, Details), ParseError> {
+        let steps : Vec<u64> = nums
+            .windows(2)
+            .map(|v| v[1] - v[0])
+            .collect();
+
+        let one_diffs = steps.iter().filter(|&&v| v == 1).count();
+        let three_diffs = steps.iter().filter(|&&v| v == 3).count();
+
+        Ok(((one_diffs * three_diffs).into(), vec![
+            ("one_diffs", one_diffs.into()),
+            ("three_diffs", three_diffs.into()),
+        ]))
+    }
+
+    fn part2(nums : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        let mut reachable : HashMap<u64, Vec<u64>> = HashMap::new();
+
+        reachable.reserve(nums.len());
+
+        for i in 1..nums.len() {
+            let mut from : Vec<u64> = Vec::with_capacity(3);
+            let adapter = nums[i] as i64;
+            let start = max(0, i as isize -3) as usize;
+            for &num in &nums[start..i] {
+                if num as i64 >= adapter - 3 {
+                    from.push(num);
+                }
+            }
+            reachable.insert(adapter as u64, from);
+        }
+
+        let mut paths: HashMap<u64, u64> = HashMap::new();
+
+        Some(Ok(traverse(0, *nums.last().unwrap(), &reachable, &mut paths).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    #[test]
+    fn rejects_repeated_joltages() {
+        assert_eq!(read("16\n10\n15\n").unwrap(), vec![0, 10, 15, 16, 19]);
+        assert_eq!(read("1\n 1\n").unwrap_err().to_string(), "2:2: joltage used twice (found '1')");
+        assert_eq!(read("3\n0\n").unwrap_err().to_string(), "2:1: expected a joltage above the outlet's 0 (found '0')");
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::ParseError;
+use crate::solution::{Answer, Details, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seat {
+    Floor, Empty, Occupied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub width : usize,
+    pub seats : Vec<Seat>,
+}
+
+/// Which seats a passenger looks at before sitting down or getting up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbours {
+    /// The eight seats around them.
+    Adjacent,
+    /// The first seat in each of the eight directions, looking over the floor.
+    Visible,
+}
+
+impl Neighbours {
+    /// How many occupied neighbours make a passenger leave their seat.
+    pub fn tolerance(self) -> usize {
+        match self {
+            Neighbours::Adjacent => 4,
+            Neighbours::Visible => 5,
+        }
+    }
+}
+
+const RAYS : [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl Layout {
+    pub fn height(&self) -> usize {
+        self.seats.len() / self.width.max(1)
+    }
+
+    pub fn occupied(&self) -> usize {
+        self.seats.iter().filter(|&&s| s == Seat::Occupied).count()
+    }
+
+    /// For each seat, the indices of the seats it takes into account.
+    pub fn neighbours(&self, mode : Neighbours) -> Vec<Vec<usize>> {
+        let (width, height) = (self.width as isize, self.height() as isize);
+        (0..self.seats.len())
+            .map(|i| {
+                if self.seats[i] == Seat::Floor {
+                    return Vec::new();
+                }
+                let (x, y) = ((i % self.width) as isize, (i / self.width) as isize);
+                RAYS.iter()
+                    .filter_map(|&(dx, dy)| {
+                        let (mut x, mut y) = (x + dx, y + dy);
+                        while (0..width).contains(&x) && (0..height).contains(&y) {
+                            let j = (y * width + x) as usize;
+                            if self.seats[j] != Seat::Floor || mode == Neighbours::Adjacent {
+                                return Some(j).filter(|&j| self.seats[j] != Seat::Floor);
+                            }
+                            x += dx;
+                            y += dy;
+                        }
+                        None
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Seats and frees everyone at once, or `None` if nobody moves.
+    pub fn step(&self, neighbours : &[Vec<usize>], tolerance : usize) -> Option<Layout> {
+        let mut changed = false;
+        let seats = self.seats.iter()
+            .zip(neighbours)
+            .map(|(&seat, around)| {
+                let occupied = around.iter().filter(|&&j| self.seats[j] == Seat::Occupied).count();
+                let next = match seat {
+                    Seat::Empty if occupied == 0 => Seat::Occupied,
+                    Seat::Occupied if occupied >= tolerance => Seat::Empty,
+                    _ => seat,
+                };
+                changed |= next != seat;
+                next
+            })
+            .collect();
+        if changed { Some(Layout { width: self.width, seats }) } else { None }
+    }
+
+    /// Steps until nobody moves, returning the final layout and the number of steps in which someone did.
+    pub fn settle(&self, mode : Neighbours) -> (Layout, usize) {
+        let neighbours = self.neighbours(mode);
+        let mut layout = self.clone();
+        let mut generations = 0;
+        while let Some(next) = layout.step(&neighbours, mode.tolerance()) {
+            layout = next;
+            generations += 1;
+        }
+        (layout, generations)
+    }
+}
+
+pub fn read(input : &str) -> Result<Layout, ParseError> {
+    let mut layout = Layout { width: 0, seats: Vec::new() };
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.char_indices() {
+            layout.seats.push(match c {
+                'L' => Seat::Empty,
+                '#' => Seat::Occupied,
+                '.' => Seat::Floor,
+                _ => return Err(ParseError::at(y + 1, line, &line[x..x + c.len_utf8()], "expected 'L', '#' or '.'")),
+            });
+        }
+        if y == 0 {
+            layout.width = line.len();
+        }
+        if line.is_empty() || line.len() != layout.width {
+            return Err(ParseError::at(y + 1, line, line, format!("expected a row of {} seats", layout.width.max(1))));
+        }
+    }
+
+    Ok(layout)
+}
+
+fn settled(layout : &Layout, mode : Neighbours) -> (Answer, Details) {
+    let (layout, generations) = layout.settle(mode);
+    (layout.occupied().into(), vec![("generations", generations.into())])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Layout;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(layout : &Self::Input) -> Result<Answer, ParseError> {
+        Self::part1_details(layout).map(|(answer, _)| answer)
+    }
+
+    fn part2(layout : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Self::part2_details(layout).map(|result| result.map(|(answer, _)| answer))
+    }
+
+    fn part1_details(layout : &Self::Input) -> Result<(Answer, Details), ParseError> {
+        Ok(settled(layout, Neighbours::Adjacent))
+    }
+
+    fn part2_details(layout : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        Some(Ok(settled(layout, Neighbours::Visible)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, Neighbours};
+
+    const EXAMPLE : &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+    #[test]
+    fn settles() {
+        let layout = read(EXAMPLE).unwrap();
+        let (adjacent, generations) = layout.settle(Neighbours::Adjacent);
+        assert_eq!((adjacent.occupied(), generations), (37, 5));
+        let (visible, generations) = layout.settle(Neighbours::Visible);
+        assert_eq!((visible.occupied(), generations), (26, 6));
+    }
+
+    #[test]
+    fn looks_over_floor() {
+        let layout = read(".......#.\n...#.....\n.#.......\n.........\n..#L....#\n....#....\n.........\n#........\n...#.....\n").unwrap();
+        let seat = 4 * 9 + 3;
+        assert_eq!(layout.neighbours(Neighbours::Visible)[seat].len(), 8);
+        assert_eq!(layout.neighbours(Neighbours::Adjacent)[seat].len(), 2);
+        assert_eq!(read("L.\nL\n").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,203 @@
+use std::mem::swap;
+use std::ops::{Add, Mul};
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Details, Solution};
+
+#[derive(Default,Debug,Copy,Clone)]
+pub struct Coord ( pub i32, pub i32 );
+
+impl Coord {
+    pub fn rot(mut self, mut degrees : i32) -> Self {
+        degrees %= 360;
+        let rots = (4 + degrees/90) % 4;
+        for _i in 0..rots {
+            swap(& mut self.0, & mut self.1);
+            self.1 = -self.1;
+        }
+        self
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Self;
+    fn mul(mut self, times : i32) -> Self {
+        self.0 *= times;
+        self.1 *= times;
+        self
+    }
+}
+
+impl Add for Coord {
+    type Output = Self;
+    fn add(mut self, rhs : Coord) -> Self {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+        self
+    }
+}
+
+#[derive(Debug,Copy,Clone,Default)]
+pub enum Direction { North, #[default] East, South, West }
+
+#[derive(Debug,Copy,Clone,Eq,PartialEq)]
+pub enum Turn { Left, Right }
+
+#[derive(Debug)]
+pub enum Action { Direction(Direction), Turn(Turn), Move }
+
+impl Direction {
+    pub fn direction_coord(&self) -> Coord {
+        match self {
+            Direction::North => Coord (0, 1),
+            Direction::East => Coord (1, 0),
+            Direction::South => Coord (0, -1),
+            Direction::West => Coord (-1, 0),
+        }
+    }
+}
+
+#[derive(Default,Debug,Copy,Clone)]
+pub struct Ship {
+    pub pos : Coord,
+    pub dir : Direction,
+    pub wayp : Coord,
+}
+
+impl Ship {
+    pub fn move_by(&mut self, d : &Direction, steps : i32) {
+        self.pos = self.pos.add(d.direction_coord().mul(steps));
+    }
+    pub fn move_waypoint(&mut self, d : &Direction, steps : i32) {
+        self.wayp = self.wayp.add(d.direction_coord().mul(steps));
+    }
+    pub fn follow_waypoint(&mut self, steps : i32) {
+        self.pos = self.pos.add(self.wayp.mul(steps));
+    }
+    pub fn turn(&mut self, t : &Turn, degrees : i32) {
+        for _i in 0..(degrees/90) {
+            self.dir = match self.dir {
+                Direction::North => if t == &Turn::Left { Direction::West } else { Direction::East },
+                Direction::East => if t == &Turn::Left { Direction::North } else { Direction::South },
+                Direction::South => if t == &Turn::Left { Direction::East } else { Direction::West },
+                Direction::West => if t == &Turn::Left { Direction::South } else { Direction::North },
+            };
+        }
+    }
+    pub fn turn_waypoint(&mut self, t : &Turn, mut degrees: i32) {
+        degrees = match t {
+            Turn::Left => -degrees,
+            _ => degrees
+        };
+        self.wayp = self.wayp.rot(degrees);
+    }
+    pub fn manhattan_distance(&self) -> i32 {
+        self.pos.0.abs() + self.pos.1.abs()
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub action : Action,
+    pub value : i32
+}
+
+pub type Instructions = Vec::<Instruction>;
+
+pub fn read_instructions(input : &str) -> Result<Instructions, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let action = l.get(0..1).unwrap_or(l);
+            Ok(Instruction {
+                action: match action {
+                    "N" => Action::Direction(Direction::North),
+                    "E" => Action::Direction(Direction::East),
+                    "S" => Action::Direction(Direction::South),
+                    "W" => Action::Direction(Direction::West),
+                    "L" => Action::Turn(Turn::Left),
+                    "R" => Action::Turn(Turn::Right),
+                    "F" => Action::Move,
+                    _ => return Err(ParseError::at(i + 1, l, action, "unknown action")),
+                },
+                value: parse(i + 1, l, &l[1..])?,
+            })
+        })
+        .collect()
+}
+
+pub fn ship_movement_strategy (mut ship : Ship, instruction : &Instruction) -> Ship {
+    match &instruction.action {
+        Action::Move => ship.move_by(&ship.dir.clone(), instruction.value),
+        Action::Direction(dir) => ship.move_by(dir, instruction.value),
+        Action::Turn(turn) => ship.turn(turn, instruction.value),
+    }
+    ship
+}
+
+pub fn ship_waypoint_strategy (mut ship : Ship, instruction : &Instruction) -> Ship {
+    match &instruction.action {
+        Action::Move => ship.follow_waypoint(instruction.value),
+        Action::Direction(dir) => ship.move_waypoint(dir, instruction.value),
+        Action::Turn(turn) => ship.turn_waypoint(turn, instruction.value),
+    }
+    ship
+}
+
+pub fn navigate(mut ship: Ship, instructions : &Instructions, strategy : fn(Ship, &Instruction) -> Ship) -> Ship {
+    instructions.iter()
+        .for_each(|i| ship = strategy(ship, i));
+    ship
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Instructions;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read_instructions(input)
+    }
+
+    fn part1(instructions : &Self::Input) -> Result<Answer, ParseError> {
+        Self::part1_details(instructions).map(|(answer, _)| answer)
+    }
+
+    fn part2(instructions : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Self::part2_details(instructions).map(|result| result.map(|(answer, _)| answer))
+    }
+
+    fn part1_details(instructions : &Self::Input) -> Result<(Answer, Details), ParseError> {
+        let ship = navigate(Ship::default(), instructions, ship_movement_strategy);
+        Ok((ship.manhattan_distance().into(), vec![
+            ("x", ship.pos.0.into()),
+            ("y", ship.pos.1.into()),
+            ("direction", format!("{:?}", ship.dir).into()),
+        ]))
+    }
+
+    fn part2_details(instructions : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        let mut ship = Ship { wayp: Coord(10, 1), ..Ship::default() };
+        ship = navigate(ship, instructions, ship_waypoint_strategy);
+        Some(Ok((ship.manhattan_distance().into(), vec![
+            ("x", ship.pos.0.into()),
+            ("y", ship.pos.1.into()),
+            ("waypoint_x", ship.wayp.0.into()),
+            ("waypoint_y", ship.wayp.1.into()),
+        ])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_instructions;
+
+    #[test]
+    fn malformed() {
+        let err = read_instructions("F10\nN3\nQ7\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str(), err.message.as_str()), (3, 1, "Q", "unknown action"));
+        let err = read_instructions("F10\nR9x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "9x"));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Details, Solution};
+
+// https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if a == 0 {
+        (b, 0, 1)
+    } else {
+        let (g, x, y) = egcd(b % a, a);
+        (g, y - (b / a) * x, x)
+    }
+}
+
+/// The inverse of `x` modulo `n`, if they are co-prime.
+pub fn mod_inv(x: i64, n: i64) -> Option<i64> {
+    let (g, x, _) = egcd(x, n);
+    if g != 1 {
+        return None;
+    }
+    Some((x % n + n) % n)
+}
+
+/// The smallest non-negative number with the given residues, if the modulii are co-prime and their product fits in an i64.
+pub fn chinese_remainder(residues: &[i64], modulii: &[i64]) -> Option<i64> {
+    let n = modulii.iter().try_fold(1i64, |n, &m| n.checked_mul(m))?;
+
+    residues.iter().zip(modulii).try_fold(0i128, |a, (&r, &m)| {
+        let p = r as i128 * mod_inv(n/m, m)? as i128 % m as i128;
+        Some((a + p * (n/m) as i128) % n as i128)
+    }).map(|a| a.rem_euclid(n as i128) as i64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule { Bus(i64), None }
+pub type S = Schedule;
+
+pub fn read(input : &str) -> Result<(i64, Vec<S>), ParseError> {
+    let mut lines = input.lines();
+
+    let line = lines.next().ok_or_else(|| ParseError::new(1, 1, "", "expected earliest departure"))?;
+    let earliest : i64 = parse(1, line, line.trim())?;
+
+    let line = lines.next().ok_or_else(|| ParseError::new(2, 1, "", "expected bus schedule"))?;
+    let schedule = line
+        .split(',')
+        .map(|e| match e {
+            "x" => Ok(S::None),
+            _ => match parse(2, line, e)? {
+                bus if bus < 1 => Err(ParseError::at(2, line, e, "expected a bus ID of at least 1")),
+                bus => Ok(S::Bus(bus)),
+            },
+        })
+        .collect::<Result<Vec<S>, ParseError>>()?;
+    if schedule.iter().all(|s| matches!(s, S::None)) {
+        return Err(ParseError::at(2, line, line, "expected at least one bus"));
+    }
+
+    Ok((earliest, schedule))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (i64, Vec<S>);
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(input : &Self::Input) -> Result<Answer, ParseError> {
+        Self::part1_details(input).map(|(answer, _)| answer)
+    }
+
+    fn part1_details((earliest, schedule) : &Self::Input) -> Result<(Answer, Details), ParseError> {
+        let upcomping : Vec<i64> = schedule.iter().map(|s| match s {
+            S::None => i64::MAX,
+            S::Bus(freq) => freq - earliest % freq,
+        }).collect();
+
+        let quickest_schedule = upcomping.iter().enumerate()
+            .min_by(|&(_, a), &(_, b)| a.cmp(b)).unwrap();
+
+        if let S::Bus(quickest_bus) = schedule[quickest_schedule.0] {
+            Ok(((quickest_schedule.1 * quickest_bus).into(), vec![
+                ("earliest", (*earliest).into()),
+                ("bus", quickest_bus.into()),
+                ("wait", (*quickest_schedule.1).into()),
+            ]))
+        } else {
+            Err(ParseError::new(2, 1, "", "expected at least one bus"))
+        }
+    }
+
+    fn part2((_, schedule) : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        let residues : Vec<i64> = schedule.iter().enumerate().map(|(i, s)| match s {
+            S::None => 0,
+            S::Bus(bus) => bus - i as i64,
+        }).collect();
+
+        let modulii : Vec<i64> = schedule.iter().map(|s| match s {
+            S::None => 1,
+            S::Bus(bus) => *bus,
+        }).collect();
+
+        Some(chinese_remainder(&residues, &modulii).map(Answer::from)
+            .ok_or_else(|| ParseError::new(2, 1, "", "expected co-prime bus IDs whose product fits in an i64")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chinese_remainder, read, Day13};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn earliest_timestamp() {
+        assert_eq!(chinese_remainder(&[0, 12, 55, 25, 12], &[7, 13, 59, 31, 19]), Some(1068781));
+        assert_eq!(chinese_remainder(&[0, 1], &[4, 6]), None);
+        assert_eq!(chinese_remainder(&[0, 1], &[1 << 40, (1 << 40) + 1]), None);
+    }
+
+    #[test]
+    fn rejects_schedules() {
+        let err = read("939\n7,0,x\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:3: expected a bus ID of at least 1 (found '0')");
+        assert_eq!(read("939\nx,x\n").unwrap_err().to_string(), "2:1: expected at least one bus (found 'x,x')");
+
+        let notes = read("939\n4,6\n").unwrap();
+        assert_eq!(Day13::part1(&notes), Ok(Answer::Number(4)));
+        assert!(Day13::part2(&notes).unwrap().is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let err = read("939\n7,ab,x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "ab"));
+    }
+}
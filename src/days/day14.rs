@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::mem::swap;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Default, Clone)]
+pub struct Mask {
+    pub value : String,
+}
+
+impl Mask {
+    pub fn mask_for(&self, x : char) -> u64 {
+        self.value.chars().fold(0, |a, c| {
+            if c == x {
+                a << 1 | 1
+            } else {
+                a << 1
+            }
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Mem {
+    pub addr: u64,
+    pub val: u64,
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(Mask),
+    Mem(Mem),
+}
+
+pub type Instructions = Vec::<Instruction>;
+
+#[derive(Default)]
+pub struct VM {
+    pub mask : Mask,
+    pub mem : HashMap<u64, u64>,
+}
+
+impl VM {
+    pub fn execute(&mut self, instructions : &Instructions, mem_strategy : fn(&mut VM, Mem)) {
+        for i in instructions {
+            match i {
+                Instruction::Mask(mask) => self.mask = mask.clone(),
+                Instruction::Mem(mem) => mem_strategy(self, *mem),
+            }
+        }
+    }
+    pub fn set(&mut self, mem : Mem) {
+        let mut val = mem.val;
+        val = (val | self.mask.mask_for('1')) & !self.mask.mask_for('0');
+        self.mem.insert(mem.addr, val);
+    }
+    pub fn mem_access_buff(&mut self, mem : Mem) {
+        match self.mask.mask_for('X') {
+            0 => { self.mem.insert(mem.addr | self.mask.mask_for('1'), mem.val); },
+            floating_mask => {
+                let msb_pos = (floating_mask.leading_zeros() - (64-36)) as usize;
+                let msb_mask = 1 << (35 - msb_pos);
+
+                let mut mask = self.mask.clone();
+                mask.value = mask.value.replacen("X", "0", 1);
+
+                swap(&mut self.mask, &mut mask);
+                self.mem_access_buff(Mem { addr: mem.addr | msb_mask, val: mem.val });
+                self.mem_access_buff(Mem { addr: mem.addr & !msb_mask, val: mem.val });
+                swap(&mut self.mask, &mut mask);
+            }
+        };
+    }
+    pub fn sum(&self) -> u64 {
+        self.mem.iter().fold(0, |a,(_,v)| a + v)
+    }
+}
+
+pub fn read_instructions(input : &str) -> Result<Instructions, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            if let Some(value) = l.strip_prefix("mask = ") {
+                if value.len() != 36 || value.chars().any(|c| !"01X".contains(c)) {
+                    return Err(ParseError::at(i + 1, l, value, "expected 36 characters of 0, 1 or X"));
+                }
+                Ok(Instruction::Mask(Mask{ value: value.to_string() }))
+            } else if let Some(mem) = l.strip_prefix("mem[") {
+                let (addr, val) = mem.split_once("] = ")
+                    .ok_or_else(|| ParseError::at(i + 1, l, mem, "expected 'mem[addr] = value'"))?;
+                Ok(Instruction::Mem(Mem{
+                    addr: parse(i + 1, l, addr)?,
+                    val: parse(i + 1, l, val)?,
+                }))
+            } else {
+                Err(ParseError::at(i + 1, l, l, "expected mask or mem instruction"))
+            }
+        })
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Instructions;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read_instructions(input)
+    }
+
+    fn part1(instructions : &Self::Input) -> Result<Answer, ParseError> {
+        let mut vm = VM::default();
+        vm.execute(instructions, VM::set);
+
+        Ok(vm.sum().into())
+    }
+
+    fn part2(instructions : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        let mut vm = VM::default();
+        vm.execute(instructions, VM::mem_access_buff);
+
+        Some(Ok(vm.sum().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_instructions;
+
+    #[test]
+    fn malformed() {
+        let err = read_instructions("mask = XX1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 8, "XX1"));
+        let err = read_instructions("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[a] = 11\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 5, "a"));
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub fn game(mut seq : Vec<u64>, to: u64) -> u64 {
+    seq.reserve(to as usize);
+
+    let mut birth: HashMap<u64, u64> = HashMap::new();
+
+    seq[0..seq.len()-1].iter().enumerate().for_each(|(i, &s)| {
+        birth.insert(s, i as u64);
+    });
+
+    for turn in seq.len() as u64..to {
+        let spoken = *seq.last().unwrap();
+        let born = match birth.get(&spoken) {
+            Some(b) => b + 1,
+            None => turn,
+        };
+        seq.push(turn - born);
+        birth.insert(spoken, turn-1);
+    }
+
+    *seq.last().unwrap()
+}
+
+pub fn read(input : &str) -> Result<Vec<u64>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected starting numbers"))?;
+
+    line.split(',')
+        .map(|c| parse(1, line, c.trim()))
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(seq : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(game(seq.clone(), 2020).into())
+    }
+
+    fn part2(seq : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(game(seq.clone(), 30_000_000).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    #[test]
+    fn malformed() {
+        let err = read("0,3,x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 5, "x"));
+        assert_eq!(read("").unwrap_err().message, "expected starting numbers");
+    }
+}
@@ -0,0 +1,211 @@
+use std::ops::RangeInclusive;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::tools::groups;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name : String,
+    pub ranges : Vec<RangeInclusive<u64>>,
+    /// Where the field's rule is, to point at it when it can't be placed.
+    pub line : usize,
+}
+
+impl Field {
+    pub fn accepts(&self, value : u64) -> bool {
+        self.ranges.iter().any(|r| r.contains(&value))
+    }
+}
+
+pub type Ticket = Vec<u64>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes {
+    pub fields : Vec<Field>,
+    pub yours : Ticket,
+    pub nearby : Vec<Ticket>,
+}
+
+impl Notes {
+    /// The values of the ticket that fit no field at all.
+    pub fn invalid_values<'a>(&'a self, ticket : &'a Ticket) -> impl Iterator<Item = u64> + 'a {
+        ticket.iter().copied().filter(move |&v| !self.fields.iter().any(|f| f.accepts(v)))
+    }
+
+    pub fn error_rate(&self) -> u64 {
+        self.nearby.iter().flat_map(|t| self.invalid_values(t)).sum()
+    }
+
+    /// Your ticket and the nearby tickets that have no invalid values.
+    pub fn valid_tickets(&self) -> Vec<&Ticket> {
+        std::iter::once(&self.yours)
+            .chain(self.nearby.iter().filter(|t| self.invalid_values(t).next().is_none()))
+            .collect()
+    }
+
+    /// The column of each field, in the order of `fields`.
+    pub fn columns(&self) -> Result<Vec<usize>, ParseError> {
+        assign(&self.fields, &self.valid_tickets())
+    }
+
+    /// Your ticket's value for the field `name`, given the `columns` of the fields.
+    pub fn value(&self, columns : &[usize], name : &str) -> Option<u64> {
+        self.fields.iter().position(|f| f.name == name).map(|i| self.yours[columns[i]])
+    }
+}
+
+/// Places each field in the one column all the valid tickets allow, fixing a field as soon as it has a
+/// single column left, or a column as soon as a single field can go there.
+pub fn assign(fields : &[Field], tickets : &[&Ticket]) -> Result<Vec<usize>, ParseError> {
+    let width = fields.len();
+    let mut candidates : Vec<Vec<bool>> = fields.iter()
+        .map(|f| (0..width).map(|c| tickets.iter().all(|t| f.accepts(t[c]))).collect())
+        .collect();
+    let mut columns : Vec<Option<usize>> = vec![None; width];
+
+    let fix = |candidates : &mut Vec<Vec<bool>>, columns : &mut Vec<Option<usize>>, field : usize, column : usize| {
+        columns[field] = Some(column);
+        for (f, c) in candidates.iter_mut().enumerate() {
+            c[column] = f == field;
+        }
+        candidates[field] = (0..width).map(|c| c == column).collect();
+    };
+
+    loop {
+        if let Some(field) = (0..width).find(|&f| !candidates[f].contains(&true)) {
+            return Err(ParseError::new(fields[field].line, 1, &fields[field].name, "no column fits field"));
+        }
+        let single_column = (0..width)
+            .filter(|&f| columns[f].is_none())
+            .find_map(|f| match candidates[f].iter().filter(|&&c| c).count() {
+                1 => Some((f, candidates[f].iter().position(|&c| c).unwrap())),
+                _ => None,
+            });
+        let single_field = || (0..width)
+            .filter(|&c| !columns.contains(&Some(c)))
+            .find_map(|c| {
+                let fits : Vec<usize> = (0..width).filter(|&f| candidates[f][c]).collect();
+                match fits[..] {
+                    [f] => Some((f, c)),
+                    _ => None,
+                }
+            });
+        match single_column.or_else(single_field) {
+            Some((field, column)) => fix(&mut candidates, &mut columns, field, column),
+            None => break,
+        }
+    }
+
+    match columns.iter().position(Option::is_none) {
+        None => Ok(columns.into_iter().map(Option::unwrap).collect()),
+        Some(field) => {
+            let options : Vec<String> = (0..width).filter(|&c| candidates[field][c]).map(|c| (c + 1).to_string()).collect();
+            Err(ParseError::new(fields[field].line, 1, &fields[field].name,
+                                format!("field could be in any of columns {}", options.join(", "))))
+        },
+    }
+}
+
+fn read_field(line_no : usize, line : &str) -> Result<Field, ParseError> {
+    let (name, ranges) = line.split_once(": ")
+        .ok_or_else(|| ParseError::at(line_no, line, line, "expected '<field>: <lo>-<hi> or <lo>-<hi>'"))?;
+    let ranges = ranges.split(" or ")
+        .map(|range| {
+            let (lo, hi) = range.split_once('-')
+                .ok_or_else(|| ParseError::at(line_no, line, range, "expected '<lo>-<hi>'"))?;
+            Ok(parse(line_no, line, lo)?..=parse(line_no, line, hi)?)
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Field { name: name.to_string(), ranges, line: line_no })
+}
+
+fn read_ticket(line_no : usize, line : &str, width : usize) -> Result<Ticket, ParseError> {
+    let ticket = line.split(',').map(|v| parse(line_no, line, v)).collect::<Result<Ticket, ParseError>>()?;
+    if ticket.len() != width {
+        return Err(ParseError::at(line_no, line, line, format!("expected {} values", width)));
+    }
+    Ok(ticket)
+}
+
+fn read_header(group : &[(usize, &str)], header : &str) -> Result<(), ParseError> {
+    match group {
+        [(_, l), ..] if *l == header => Ok(()),
+        [(i, l), ..] => Err(ParseError::at(*i, l, l, format!("expected '{}'", header))),
+        [] => Err(ParseError::new(1, 1, "", format!("expected '{}'", header))),
+    }
+}
+
+pub fn read(input : &str) -> Result<Notes, ParseError> {
+    let groups = groups(input);
+    let (rules, yours, nearby) = match &groups[..] {
+        [rules, yours, nearby] => (rules, yours, nearby),
+        _ => return Err(ParseError::new(input.lines().count() + 1, 1, "", "expected rules, your ticket and nearby tickets")),
+    };
+
+    let fields = rules.iter().map(|&(i, l)| read_field(i, l)).collect::<Result<Vec<Field>, ParseError>>()?;
+    let width = fields.len();
+
+    read_header(yours, "your ticket:")?;
+    let yours = match &yours[..] {
+        [_, (i, l)] => read_ticket(*i, l, width)?,
+        [(i, l), ..] => return Err(ParseError::at(*i, l, l, "expected a single ticket")),
+        [] => unreachable!(),
+    };
+
+    read_header(nearby, "nearby tickets:")?;
+    let nearby = nearby[1..].iter().map(|&(i, l)| read_ticket(i, l, width)).collect::<Result<Vec<Ticket>, ParseError>>()?;
+
+    Ok(Notes { fields, yours, nearby })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(notes : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(notes.error_rate().into())
+    }
+
+    fn part2(notes : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(notes.columns().map(|columns| notes.fields.iter()
+            .filter(|f| f.name.starts_with("departure"))
+            .map(|f| notes.value(&columns, &f.name).unwrap())
+            .product::<u64>()
+            .into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, Day16};
+    use crate::solution::{Answer, Solution};
+
+    #[test]
+    fn error_rate() {
+        let notes = read("class: 1-3 or 5-7\nrow: 6-11 or 33-44\nseat: 13-40 or 45-50\n\nyour ticket:\n7,1,14\n\n\
+                          nearby tickets:\n7,3,47\n40,4,50\n55,2,20\n38,6,12\n").unwrap();
+        assert_eq!(notes.error_rate(), 71);
+    }
+
+    #[test]
+    fn assigns_columns() {
+        let notes = read("class: 0-1 or 4-19\nrow: 0-5 or 8-19\nseat: 0-13 or 16-19\n\nyour ticket:\n11,12,13\n\n\
+                          nearby tickets:\n3,9,18\n15,1,5\n5,14,9\n").unwrap();
+        let columns = notes.columns().unwrap();
+        assert_eq!(columns, vec![1, 0, 2]);
+        assert_eq!(notes.value(&columns, "seat"), Some(13));
+
+        let notes = read("a: 1-5\nb: 1-5\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4\n9,9\n").unwrap();
+        assert_eq!(Day16::part1(&notes), Ok(Answer::Number(18)));
+        let err = Day16::part2(&notes).unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "1:1: field could be in any of columns 1, 2 (found 'a')");
+        let err = read("a: 1-5\nb: 1-5\n\nyour ticket:\n1,7\n\nnearby tickets:\n").unwrap().columns().unwrap_err();
+        assert_eq!(err.to_string(), "2:1: no column fits field (found 'b')");
+    }
+}
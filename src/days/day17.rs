@@ -0,0 +1,90 @@
+use std::collections::{HashSet, HashMap};
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub type Coord = (i8,i8,i8,i8);
+pub type World = HashSet<Coord>;
+pub type Neighbors = HashMap<Coord, u8>;
+
+pub fn read(input : &str) -> Result<World, ParseError> {
+    let mut world = World::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.char_indices() {
+            match c {
+                '#' => { world.insert((x as i8,y as i8,0,0)); },
+                '.' => {},
+                _ => return Err(ParseError::at(y + 1, line, &line[x..x + c.len_utf8()], "expected '#' or '.'")),
+            }
+        }
+    }
+
+    Ok(world)
+}
+
+pub fn evolve(mut world : World, dim : i32, evolutions : i32) -> World {
+    for _i in 0..evolutions {
+        let mut neighbors : Neighbors = Neighbors::new();
+        for (x,y,z,w) in world.iter() {
+            for dx in -1..=1i8 {
+                for dy in -1..=1i8 {
+                    for dz in -1..=1i8 {
+                        for dw in if dim == 4 {-1..=1i8} else {0..=0} {
+                            if dx==0 && dy==0 && dz==0 && dw==0 {
+                                continue;
+                            }
+                            let coord = (x+dx,y+dy,z+dz,w+dw);
+                            neighbors.insert(coord, neighbors.get(&coord).unwrap_or(&0) + 1);
+                        }
+                    }
+                }
+            }
+        }
+        world = neighbors.iter().filter_map(|(&coord, &adjacent)| {
+            if adjacent == 3 || (adjacent == 2 && world.contains(&coord)) {
+                Some(coord)
+            } else {
+                None
+            }
+        }).collect()
+    }
+    world
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = World;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(world : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(evolve(world.clone(), 3, 6).len().into())
+    }
+
+    fn part2(world : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(evolve(world.clone(), 4, 6).len().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, evolve};
+
+    #[test]
+    fn part1_3d_world_example() {
+        let mut world = read(".#.\n..#\n###\n").unwrap();
+        world = evolve(world, 3, 6);
+        assert_eq!(world.len(), 112);
+    }
+
+    #[test]
+    fn part2_4d_world_example() {
+        let mut world = read(".#.\n..#\n###\n").unwrap();
+        world = evolve(world, 4, 6);
+        assert_eq!(world.len(), 848);
+    }
+}
@@ -0,0 +1,198 @@
+use std::slice;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add, Mul,
+}
+
+impl Op {
+    pub fn apply(self, lhs : u64, rhs : u64) -> u64 {
+        match self {
+            Op::Add => lhs + rhs,
+            Op::Mul => lhs * rhs,
+        }
+    }
+}
+
+/// How tightly each operator binds, the higher the earlier it applies. Equal levels go left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Precedence {
+    pub add : u8,
+    pub mul : u8,
+}
+
+impl Precedence {
+    pub fn of(&self, op : Op) -> u8 {
+        match op {
+            Op::Add => self.add,
+            Op::Mul => self.mul,
+        }
+    }
+}
+
+pub const LEFT_TO_RIGHT : Precedence = Precedence { add: 1, mul: 1 };
+pub const ADDITION_FIRST : Precedence = Precedence { add: 2, mul: 1 };
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand {
+    Number(u64),
+    Group(Box<Expr>),
+}
+
+/// An expression as written, operands and operators in a row, so it can be evaluated with any precedence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr {
+    pub first : Operand,
+    pub rest : Vec<(Op, Operand)>,
+}
+
+impl Operand {
+    pub fn eval(&self, precedence : &Precedence) -> u64 {
+        match self {
+            Operand::Number(n) => *n,
+            Operand::Group(expr) => expr.eval(precedence),
+        }
+    }
+}
+
+impl Expr {
+    pub fn eval(&self, precedence : &Precedence) -> u64 {
+        let mut rest = self.rest.iter();
+        climb(self.first.eval(precedence), 0, &mut rest, precedence)
+    }
+}
+
+/// Folds operators of at least `min` precedence into `lhs`, first folding tighter ones into their right operand.
+fn climb(mut lhs : u64, min : u8, rest : &mut slice::Iter<(Op, Operand)>, precedence : &Precedence) -> u64 {
+    while let Some((op, operand)) = rest.as_slice().first().filter(|(op, _)| precedence.of(*op) >= min) {
+        rest.next();
+        let mut rhs = operand.eval(precedence);
+        while let Some((next, _)) = rest.as_slice().first() {
+            if precedence.of(*next) <= precedence.of(*op) {
+                break;
+            }
+            rhs = climb(rhs, precedence.of(*op) + 1, rest, precedence);
+        }
+        lhs = op.apply(lhs, rhs);
+    }
+    lhs
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number, Op(Op), Open, Close,
+}
+
+/// Splits a line in tokens, each along with its text.
+fn tokenize(line_no : usize, line : &str) -> Result<Vec<(Token, &str)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            ' ' => continue,
+            '+' => Token::Op(Op::Add),
+            '*' => Token::Op(Op::Mul),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '0'..='9' => {
+                let mut end = i + 1;
+                while let Some(&(j, '0'..='9')) = chars.peek() {
+                    end = j + 1;
+                    chars.next();
+                }
+                tokens.push((Token::Number, &line[i..end]));
+                continue;
+            },
+            _ => return Err(ParseError::at(line_no, line, &line[i..i + c.len_utf8()], "expected a number, '+', '*', '(' or ')'")),
+        };
+        tokens.push((token, &line[i..i + 1]));
+    }
+
+    Ok(tokens)
+}
+
+fn read_operand<'a>(line_no : usize, line : &'a str, tokens : &mut slice::Iter<(Token, &'a str)>) -> Result<Operand, ParseError> {
+    match tokens.next() {
+        Some((Token::Number, n)) => Ok(Operand::Number(parse(line_no, line, n)?)),
+        Some((Token::Open, _)) => Ok(Operand::Group(Box::new(read_expr(line_no, line, tokens, true)?))),
+        Some((_, token)) => Err(ParseError::at(line_no, line, token, "expected a number or '('")),
+        None => Err(ParseError::at(line_no, line, &line[line.len()..], "expected a number or '('")),
+    }
+}
+
+fn read_expr<'a>(line_no : usize, line : &'a str, tokens : &mut slice::Iter<(Token, &'a str)>, nested : bool) -> Result<Expr, ParseError> {
+    let first = read_operand(line_no, line, tokens)?;
+    let mut rest = Vec::new();
+
+    loop {
+        match tokens.next() {
+            Some((Token::Op(op), _)) => rest.push((*op, read_operand(line_no, line, tokens)?)),
+            Some((Token::Close, _)) if nested => break,
+            None if !nested => break,
+            Some((_, token)) => return Err(ParseError::at(line_no, line, token, "expected '+' or '*'")),
+            None => return Err(ParseError::at(line_no, line, &line[line.len()..], "expected ')'")),
+        }
+    }
+
+    Ok(Expr { first, rest })
+}
+
+pub fn read(input : &str) -> Result<Vec<Expr>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let tokens = tokenize(i + 1, line)?;
+            read_expr(i + 1, line, &mut tokens.iter(), false)
+        })
+        .collect()
+}
+
+pub fn sum(exprs : &[Expr], precedence : &Precedence) -> u64 {
+    exprs.iter().map(|e| e.eval(precedence)).sum()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Expr>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(exprs : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(sum(exprs, &LEFT_TO_RIGHT).into())
+    }
+
+    fn part2(exprs : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(sum(exprs, &ADDITION_FIRST).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, Precedence, ADDITION_FIRST, LEFT_TO_RIGHT};
+
+    #[test]
+    fn precedence_tables() {
+        let eval = |line : &str, precedence| read(line).unwrap()[0].eval(&precedence);
+        assert_eq!(eval("1 + 2 * 3 + 4 * 5 + 6", LEFT_TO_RIGHT), 71);
+        assert_eq!(eval("1 + 2 * 3 + 4 * 5 + 6", ADDITION_FIRST), 231);
+        assert_eq!(eval("1 + 2 * 3 + 4 * 5 + 6", Precedence { add: 1, mul: 2 }), 33);
+        assert_eq!(eval("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", LEFT_TO_RIGHT), 13632);
+        assert_eq!(eval("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", ADDITION_FIRST), 23340);
+        assert_eq!(eval("12 * (3)", LEFT_TO_RIGHT), 36);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(read("1 + (2 * 3").unwrap_err().message, "expected ')'");
+        assert_eq!(read("1 + 2)").unwrap_err().column, 6);
+        assert_eq!(read("1 + * 2").unwrap_err().column, 5);
+        assert_eq!(read("1 - 2").unwrap_err().token, "-");
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::tools::groups;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    Char(u8),
+    /// Sequences of other rules, any of which may match.
+    Alternatives(Vec<Vec<usize>>),
+}
+
+/// Rules by number, rule 0 being the one messages have to match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grammar {
+    pub rules : HashMap<usize, Rule>,
+}
+
+/// The part 2 replacements, which make rules 8 and 11 loop.
+pub const LOOPS : &str = "8: 42 | 42 8\n11: 42 31 | 42 11 31";
+
+impl Grammar {
+    /// Every position a match of `rule` starting at `start` can end at. Following all of them at once
+    /// handles recursive rules without knowing how often they repeat, as long as they are not left recursive,
+    /// which [`read`] and [`Grammar::with`] make sure of.
+    pub fn ends(&self, rule : usize, message : &[u8], start : usize) -> Vec<usize> {
+        match self.rules.get(&rule) {
+            Some(Rule::Char(c)) => if message.get(start) == Some(c) { vec![start + 1] } else { Vec::new() },
+            Some(Rule::Alternatives(alternatives)) => {
+                let mut ends = Vec::new();
+                for sequence in alternatives {
+                    let mut positions = vec![start];
+                    for &r in sequence {
+                        positions = positions.iter()
+                            .filter(|&&p| p < message.len())
+                            .flat_map(|&p| self.ends(r, message, p))
+                            .collect();
+                        positions.sort_unstable();
+                        positions.dedup();
+                    }
+                    ends.extend(positions);
+                }
+                ends.sort_unstable();
+                ends.dedup();
+                ends
+            },
+            None => Vec::new(),
+        }
+    }
+
+    /// The rules each alternative of `rule` starts with.
+    fn firsts(&self, rule : usize) -> Vec<usize> {
+        match self.rules.get(&rule) {
+            Some(Rule::Alternatives(alternatives)) => alternatives.iter().filter_map(|sequence| sequence.first().copied()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The rules that can get back to themselves without consuming input, in ascending order. Every rule
+    /// consumes at least one character, so those are the ones starting with themselves through other rules.
+    pub fn left_recursive(&self) -> Vec<usize> {
+        let mut rules : Vec<usize> = self.rules.keys()
+            .copied()
+            .filter(|&rule| {
+                let (mut stack, mut seen) = (self.firsts(rule), HashSet::new());
+                while let Some(r) = stack.pop() {
+                    if r == rule {
+                        return true;
+                    }
+                    if seen.insert(r) {
+                        stack.extend(self.firsts(r));
+                    }
+                }
+                false
+            })
+            .collect();
+        rules.sort_unstable();
+        rules
+    }
+
+    /// Fails at the first left recursive rule found in `lines`, which holds the line each rule was read from.
+    fn check_left_recursion(&self, lines : &HashMap<usize, (usize, &str)>) -> Result<(), ParseError> {
+        let rules = self.left_recursive();
+        match rules.iter().find_map(|r| lines.get(r)) {
+            Some(&(i, line)) => Err(ParseError::at(i, line, &line[..line.find(':').unwrap_or(0)], "rule can reach itself without consuming input")),
+            None if !rules.is_empty() => Err(ParseError::new(1, 1, "", format!("rule {} can reach itself without consuming input", rules[0]))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn matches(&self, message : &str) -> bool {
+        self.ends(0, message.as_bytes(), 0).contains(&message.len())
+    }
+
+    /// The grammar with the rules of `replacements` added or replacing the ones with the same number.
+    pub fn with(&self, replacements : &str) -> Result<Grammar, ParseError> {
+        let mut grammar = self.clone();
+        let mut lines = HashMap::new();
+        for (i, line) in replacements.lines().enumerate() {
+            let (n, rule) = read_rule(i + 1, line)?;
+            grammar.rules.insert(n, rule);
+            lines.insert(n, (i + 1, line));
+        }
+        grammar.check_left_recursion(&lines)?;
+        Ok(grammar)
+    }
+}
+
+fn read_rule(line_no : usize, line : &str) -> Result<(usize, Rule), ParseError> {
+    let (n, rule) = line.split_once(": ")
+        .ok_or_else(|| ParseError::at(line_no, line, line, "expected '<n>: <rule>'"))?;
+    let n = parse(line_no, line, n)?;
+
+    if let Some(c) = rule.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        return match c.as_bytes() {
+            [c] => Ok((n, Rule::Char(*c))),
+            _ => Err(ParseError::at(line_no, line, rule, "expected a single character")),
+        };
+    }
+
+    let alternatives = rule.split('|')
+        .map(|sequence| sequence.split_whitespace().map(|r| parse(line_no, line, r)).collect())
+        .collect::<Result<Vec<Vec<usize>>, ParseError>>()?;
+    if alternatives.iter().any(Vec::is_empty) {
+        return Err(ParseError::at(line_no, line, rule, "expected rule numbers on both sides of '|'"));
+    }
+    Ok((n, Rule::Alternatives(alternatives)))
+}
+
+pub type Messages = Vec<String>;
+
+pub fn read(input : &str) -> Result<(Grammar, Messages), ParseError> {
+    let groups = groups(input);
+    let (rules, messages) = match &groups[..] {
+        [rules, messages] => (rules, messages),
+        [rules] => (rules, &Vec::new()),
+        _ => return Err(ParseError::new(input.lines().count() + 1, 1, "", "expected rules and messages")),
+    };
+
+    let mut grammar = Grammar::default();
+    let mut lines = HashMap::new();
+    for &(i, line) in rules {
+        let (n, rule) = read_rule(i, line)?;
+        if grammar.rules.insert(n, rule).is_some() {
+            return Err(ParseError::at(i, line, &line[..line.find(':').unwrap()], "rule defined twice"));
+        }
+        lines.insert(n, (i, line));
+    }
+    for &(i, line) in rules {
+        if let Some(unknown) = line.split_whitespace().skip(1).find(|r| r.parse().is_ok_and(|r| !grammar.rules.contains_key(&r))) {
+            return Err(ParseError::at(i, line, unknown, "unknown rule"));
+        }
+    }
+    grammar.check_left_recursion(&lines)?;
+
+    Ok((grammar, messages.iter().map(|(_, m)| m.to_string()).collect()))
+}
+
+pub fn count_matches(grammar : &Grammar, messages : &Messages) -> usize {
+    messages.iter().filter(|m| grammar.matches(m)).count()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Grammar, Messages);
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1((grammar, messages) : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(count_matches(grammar, messages).into())
+    }
+
+    fn part2((grammar, messages) : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(grammar.with(LOOPS).map(|looping| count_matches(&looping, messages).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_matches, read, LOOPS};
+
+    #[test]
+    fn matches_rules() {
+        let (grammar, messages) = read("0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n\n\
+                                        ababbb\nbababa\nabbbab\naaabbb\naaaabbb\n").unwrap();
+        assert_eq!(count_matches(&grammar, &messages), 2);
+        assert!(grammar.matches("aaaabb"));
+        assert!(!grammar.matches("aaaabbb"));
+    }
+
+    #[test]
+    fn loops() {
+        let (grammar, _) = read("0: 8 11\n8: 42\n11: 42 31\n42: \"a\"\n31: \"b\"\n").unwrap();
+        let looping = grammar.with(LOOPS).unwrap();
+        assert!(!grammar.matches("aaab"));
+        assert!(looping.matches("aaab"));
+        assert!(looping.matches("aaaabb"));
+        assert!(!looping.matches("aabb"));
+        assert!(!looping.matches("abab"));
+    }
+
+    #[test]
+    fn unknown_rules() {
+        let err = read("0: 1 2\n1: \"a\"\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (1, 6, "unknown rule"));
+    }
+
+    #[test]
+    fn left_recursion() {
+        let err = read("0: 0 1\n1: \"a\"\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:1: rule can reach itself without consuming input (found '0')");
+        let err = read("0: 1\n1: 2 3\n2: 3 | 1 3\n3: \"a\"\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (2, "1"));
+
+        let (grammar, _) = read("0: 8 11\n8: 42\n11: 42 31\n42: 43 | 31 42\n43: \"a\"\n31: \"b\"\n").unwrap();
+        assert!(grammar.left_recursive().is_empty());
+        assert!(grammar.with(LOOPS).is_ok());
+        let err = grammar.with("31: 43 | 8 31").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (1, "31"));
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Details, Solution};
+use crate::tools::groups;
+
+/// A square of pixels, `true` being `#`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub size : usize,
+    pub pixels : Vec<bool>,
+}
+
+impl Image {
+    pub fn get(&self, x : usize, y : usize) -> bool {
+        self.pixels[y * self.size + x]
+    }
+
+    fn from_fn(size : usize, f : impl Fn(usize, usize) -> bool) -> Image {
+        Image { size, pixels: (0..size * size).map(|i| f(i % size, i / size)).collect() }
+    }
+
+    /// The image turned a quarter clockwise.
+    pub fn rotate(&self) -> Image {
+        Image::from_fn(self.size, |x, y| self.get(y, self.size - 1 - x))
+    }
+
+    /// The image mirrored left to right.
+    pub fn flip(&self) -> Image {
+        Image::from_fn(self.size, |x, y| self.get(self.size - 1 - x, y))
+    }
+
+    /// The four rotations of the image followed by the four rotations of its mirror image.
+    pub fn orientations(&self) -> Vec<Image> {
+        let mut orientations = Vec::with_capacity(8);
+        for image in [self.clone(), self.flip()] {
+            let mut image = image;
+            for _ in 0..4 {
+                let next = image.rotate();
+                orientations.push(image);
+                image = next;
+            }
+        }
+        orientations
+    }
+
+    /// The image without its outer pixels.
+    pub fn inner(&self) -> Image {
+        Image::from_fn(self.size - 2, |x, y| self.get(x + 1, y + 1))
+    }
+
+    pub fn count(&self) -> usize {
+        self.pixels.iter().filter(|&&p| p).count()
+    }
+
+    fn edge(&self, pixel : impl Fn(usize) -> bool) -> u64 {
+        (0..self.size).fold(0, |edge, i| edge << 1 | pixel(i) as u64)
+    }
+
+    /// The top, right, bottom and left edges, read left to right and top to bottom.
+    pub fn edges(&self) -> [u64; 4] {
+        let last = self.size - 1;
+        [
+            self.edge(|x| self.get(x, 0)),
+            self.edge(|y| self.get(last, y)),
+            self.edge(|x| self.get(x, last)),
+            self.edge(|y| self.get(0, y)),
+        ]
+    }
+
+    /// Every top left corner at which all the pixels of `pattern` are set.
+    pub fn find(&self, pattern : &Pattern) -> Vec<(usize, usize)> {
+        if pattern.width > self.size || pattern.height > self.size {
+            return Vec::new();
+        }
+        (0..=self.size - pattern.height)
+            .flat_map(|y| (0..=self.size - pattern.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| pattern.cells.iter().all(|&(dx, dy)| self.get(x + dx, y + dy)))
+            .collect()
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        for row in self.pixels.chunks(self.size) {
+            let row : String = row.iter().map(|&p| if p { '#' } else { '.' }).collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tile {
+    pub id : u64,
+    pub image : Image,
+}
+
+/// The tiles in their place and orientation, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assembly {
+    pub side : usize,
+    pub tiles : Vec<Tile>,
+}
+
+impl Assembly {
+    pub fn corners(&self) -> [u64; 4] {
+        let last = self.side - 1;
+        let id = |x : usize, y : usize| self.tiles[y * self.side + x].id;
+        [id(0, 0), id(last, 0), id(0, last), id(last, last)]
+    }
+
+    /// The tiles without their borders, put together in a single image.
+    pub fn image(&self) -> Image {
+        let inner : Vec<Image> = self.tiles.iter().map(|t| t.image.inner()).collect();
+        let size = inner.first().map_or(0, |i| i.size);
+        Image::from_fn(self.side * size, |x, y| inner[(y / size) * self.side + x / size].get(x % size, y % size))
+    }
+}
+
+/// An edge the same whichever way it is read, to find the tiles that share it.
+fn edge_key(edge : u64, size : usize) -> u64 {
+    let reversed = (0..size).fold(0, |r, i| r << 1 | (edge >> i & 1));
+    edge.min(reversed)
+}
+
+/// Places the tiles row by row from a corner, each in the orientation whose edges match the tiles left
+/// of and above it, taking candidates from the tiles that share that edge and backing up when none fits.
+pub fn assemble(tiles : &[Tile]) -> Option<Assembly> {
+    let side = (0..=tiles.len()).find(|s| s * s >= tiles.len())?;
+    if side == 0 || side * side != tiles.len() {
+        return None;
+    }
+    let size = tiles[0].image.size;
+
+    let orientations : Vec<Vec<(Image, [u64; 4])>> = tiles.iter()
+        .map(|t| t.image.orientations().into_iter().map(|i| { let edges = i.edges(); (i, edges) }).collect())
+        .collect();
+
+    let mut sharing : HashMap<u64, Vec<usize>> = HashMap::new();
+    for (tile, o) in orientations.iter().enumerate() {
+        for &edge in &o[0].1 {
+            // A tile with two edges alike still counts once, or it would match itself.
+            let tiles = sharing.entry(edge_key(edge, size)).or_default();
+            if tiles.last() != Some(&tile) {
+                tiles.push(tile);
+            }
+        }
+    }
+    let unmatched = |edge : u64| sharing[&edge_key(edge, size)].len() == 1;
+
+    struct Search<'a> {
+        side : usize,
+        size : usize,
+        orientations : &'a [Vec<(Image, [u64; 4])>],
+        sharing : &'a HashMap<u64, Vec<usize>>,
+        placed : Vec<(usize, usize)>,
+        used : Vec<bool>,
+    }
+
+    impl Search<'_> {
+        fn edges(&self, pos : usize) -> [u64; 4] {
+            let (tile, orientation) = self.placed[pos];
+            self.orientations[tile][orientation].1
+        }
+
+        fn place(&mut self) -> bool {
+            let pos = self.placed.len();
+            if pos == self.orientations.len() {
+                return true;
+            }
+            let left = if !pos.is_multiple_of(self.side) { Some(self.edges(pos - 1)[1]) } else { None };
+            let top = if pos >= self.side { Some(self.edges(pos - self.side)[2]) } else { None };
+            let shared = left.or(top).expect("The first tile is placed before searching");
+
+            for &tile in &self.sharing[&edge_key(shared, self.size)] {
+                if self.used[tile] {
+                    continue;
+                }
+                for (orientation, (_, [t, _, _, l])) in self.orientations[tile].iter().enumerate() {
+                    if left.is_some_and(|e| e != *l) || top.is_some_and(|e| e != *t) {
+                        continue;
+                    }
+                    self.used[tile] = true;
+                    self.placed.push((tile, orientation));
+                    if self.place() {
+                        return true;
+                    }
+                    self.placed.pop();
+                    self.used[tile] = false;
+                }
+            }
+            false
+        }
+    }
+
+    let mut search = Search { side, size, orientations: &orientations, sharing: &sharing, placed: Vec::new(), used: vec![false; tiles.len()] };
+
+    // A corner has nothing above or left of it, so its top and left edges match no other tile.
+    let corners = (0..tiles.len())
+        .flat_map(|tile| (0..8).map(move |orientation| (tile, orientation)))
+        .filter(|&(tile, orientation)| {
+            let [t, _, _, l] = orientations[tile][orientation].1;
+            unmatched(t) && unmatched(l)
+        });
+    for (tile, orientation) in corners {
+        search.used[tile] = true;
+        search.placed.push((tile, orientation));
+        if search.place() {
+            return Some(Assembly {
+                side,
+                tiles: search.placed.into_iter()
+                    .map(|(tile, orientation)| Tile { id: tiles[tile].id, image: orientations[tile][orientation].0.clone() })
+                    .collect(),
+            });
+        }
+        search.placed.pop();
+        search.used[tile] = false;
+    }
+    None
+}
+
+/// Assembles the tiles, pointing at the first tile when they can't be put together.
+pub fn assembled(tiles : &[Tile]) -> Result<Assembly, ParseError> {
+    assemble(tiles).ok_or_else(|| ParseError::new(1, 1, "", "tiles don't fit together in a square"))
+}
+
+/// The pixels that have to be set, relative to the top left corner of a `width` by `height` box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub width : usize,
+    pub height : usize,
+    pub cells : Vec<(usize, usize)>,
+}
+
+pub const SEA_MONSTER : &str = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   \n";
+
+impl Pattern {
+    /// Reads a pattern drawn with `#`, spaces and `.` standing for pixels that may be anything.
+    pub fn read(input : &str) -> Result<Pattern, ParseError> {
+        let mut pattern = Pattern { width: 0, height: 0, cells: Vec::new() };
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.char_indices() {
+                match c {
+                    '#' => pattern.cells.push((x, y)),
+                    ' ' | '.' => {},
+                    _ => return Err(ParseError::at(y + 1, line, &line[x..x + c.len_utf8()], "expected '#', '.' or ' '")),
+                }
+            }
+        }
+        pattern.width = pattern.cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        pattern.height = pattern.cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        if pattern.cells.is_empty() {
+            return Err(ParseError::new(1, 1, "", "expected at least one '#'"));
+        }
+        Ok(pattern)
+    }
+}
+
+/// The image turned so it shows the most occurrences of a pattern, and the pixels they cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search {
+    pub image : Image,
+    pub found : Vec<(usize, usize)>,
+    pub covered : HashSet<(usize, usize)>,
+}
+
+impl Search {
+    /// The set pixels that are not part of any occurrence.
+    pub fn roughness(&self) -> usize {
+        self.image.count() - self.covered.len()
+    }
+}
+
+impl fmt::Display for Search {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.image.size {
+            let row : String = (0..self.image.size)
+                .map(|x| match (self.covered.contains(&(x, y)), self.image.get(x, y)) {
+                    (true, _) => 'O',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                })
+                .collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn search(image : &Image, pattern : &Pattern) -> Search {
+    image.orientations()
+        .into_iter()
+        .map(|image| {
+            let found = image.find(pattern);
+            let covered = found.iter()
+                .flat_map(|&(x, y)| pattern.cells.iter().map(move |&(dx, dy)| (x + dx, y + dy)))
+                .collect();
+            Search { image, found, covered }
+        })
+        .fold(None, |best : Option<Search>, s| match best {
+            Some(best) if best.found.len() >= s.found.len() => Some(best),
+            _ => Some(s),
+        })
+        .expect("An image has eight orientations")
+}
+
+fn read_tile(group : &[(usize, &str)]) -> Result<Tile, ParseError> {
+    let (line_no, header) = group[0];
+    let id = header.strip_prefix("Tile ")
+        .and_then(|h| h.strip_suffix(':'))
+        .ok_or_else(|| ParseError::at(line_no, header, header, "expected 'Tile <id>:'"))?;
+    let id = parse(line_no, header, id)?;
+
+    let size = group.len() - 1;
+    let mut pixels = Vec::with_capacity(size * size);
+    for &(line_no, line) in &group[1..] {
+        for (x, c) in line.char_indices() {
+            pixels.push(match c {
+                '#' => true,
+                '.' => false,
+                _ => return Err(ParseError::at(line_no, line, &line[x..x + c.len_utf8()], "expected '#' or '.'")),
+            });
+        }
+        if line.len() != size {
+            return Err(ParseError::at(line_no, line, line, format!("expected a row of {} pixels", size)));
+        }
+    }
+    // Edges are packed in a u64, one bit per pixel.
+    if !(3..=64).contains(&size) {
+        return Err(ParseError::at(line_no, header, header, "expected a tile of 3 by 3 to 64 by 64 pixels"));
+    }
+
+    Ok(Tile { id, image: Image { size, pixels } })
+}
+
+pub fn read(input : &str) -> Result<Vec<Tile>, ParseError> {
+    let mut tiles : Vec<Tile> = Vec::new();
+    for group in groups(input) {
+        let tile = read_tile(&group)?;
+        if tiles.first().is_some_and(|first| first.image.size != tile.image.size) {
+            return Err(ParseError::at(group[0].0, group[0].1, group[0].1, format!("expected a tile of {} by {} pixels", tiles[0].image.size, tiles[0].image.size)));
+        }
+        tiles.push(tile);
+    }
+    if !(1..=tiles.len()).any(|side| side * side == tiles.len()) {
+        return Err(ParseError::new(input.lines().count() + 1, 1, "", format!("expected a square number of tiles, found {}", tiles.len())));
+    }
+    Ok(tiles)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(tiles : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(assembled(tiles)?.corners().iter().product::<u64>().into())
+    }
+
+    fn part2(tiles : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Self::part2_details(tiles).map(|result| result.map(|(answer, _)| answer))
+    }
+
+    fn part2_details(tiles : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        let pattern = Pattern::read(SEA_MONSTER).expect("The sea monster is a valid pattern");
+        Some(assembled(tiles).map(|assembly| {
+            let search = search(&assembly.image(), &pattern);
+            (search.roughness().into(), vec![("monsters", search.found.len().into())])
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assemble, read, search, Image, Pattern, SEA_MONSTER};
+    use crate::tools::Source;
+
+    fn image(rows : &str) -> Image {
+        let rows : Vec<&str> = rows.split_whitespace().collect();
+        Image { size: rows.len(), pixels: rows.iter().flat_map(|r| r.chars().map(|c| c == '#')).collect() }
+    }
+
+    #[test]
+    fn orientations() {
+        let i = image("#.. ... ..#");
+        assert_eq!(i.rotate(), image("..# ... #.."));
+        assert_eq!(i.flip(), image("..# ... #.."));
+        assert_eq!(i.rotate().rotate().rotate().rotate(), i);
+        assert_eq!(i.orientations().len(), 8);
+        assert_eq!(image("##. #.. ...").edges(), [0b110, 0b000, 0b000, 0b110]);
+    }
+
+    #[test]
+    fn assembles() {
+        let tiles = read(&Source::from_arg("input/day20_example").read().unwrap()).unwrap();
+        let assembly = assemble(&tiles).unwrap();
+        assert_eq!(assembly.side, 3);
+        assert_eq!(assembly.image().size, 24);
+        assert_eq!(assemble(&tiles[1..]), None);
+        assert_eq!(assemble(&[]), None);
+        let single = read("Tile 7:\n#..\n...\n...\n").unwrap();
+        assert_eq!(assemble(&single).unwrap().corners(), [7, 7, 7, 7]);
+
+        let row = ".".repeat(65);
+        let wide = format!("Tile 8:\n{}\n", vec![row.as_str(); 65].join("\n"));
+        assert_eq!(read(&wide).unwrap_err().to_string(), "1:1: expected a tile of 3 by 3 to 64 by 64 pixels (found 'Tile 8:')");
+        assert_eq!(read("Tile 9:\n..\n..\n").unwrap_err().line, 1);
+        assert_eq!(read("").unwrap_err().to_string(), "1:1: expected a square number of tiles, found 0");
+        for pair in assembly.tiles.windows(2).step_by(3) {
+            assert_eq!(pair[0].image.edges()[1], pair[1].image.edges()[3]);
+        }
+    }
+
+    #[test]
+    fn finds_patterns() {
+        let pattern = Pattern::read(SEA_MONSTER).unwrap();
+        assert_eq!((pattern.width, pattern.height, pattern.cells.len()), (20, 3, 15));
+
+        let mut pixels = vec![false; 20 * 20];
+        pattern.cells.iter().for_each(|&(x, y)| pixels[(y + 5) * 20 + x] = true);
+        pixels[0] = true;
+        let found = search(&Image { size: 20, pixels }.rotate(), &pattern);
+        assert_eq!(found.found.len(), 1);
+        assert_eq!(found.roughness(), 1);
+        assert!(found.to_string().contains("O....OO....OO....OOO"));
+
+        assert_eq!(Pattern::read("#x").unwrap_err().column, 2);
+        assert!(read("Tile 1:\n#.\n..\n").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Food {
+    pub ingredients : Vec<String>,
+    pub allergens : Vec<String>,
+}
+
+/// The foods, and where each allergen is first mentioned, to point at when it can't be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes {
+    pub foods : Vec<Food>,
+    pub first_seen : HashMap<String, (usize, usize)>,
+}
+
+impl Notes {
+    /// How often ingredients that can't contain any allergen appear.
+    pub fn safe_count(&self) -> usize {
+        let unsafe_ : BTreeSet<&str> = candidates(&self.foods).into_values().flatten().collect();
+        self.foods.iter()
+            .flat_map(|f| &f.ingredients)
+            .filter(|i| !unsafe_.contains(i.as_str()))
+            .count()
+    }
+
+    /// Each allergen with the one ingredient that contains it, or an error at the first mention
+    /// of an allergen that can't be given one.
+    pub fn dangerous(&self) -> Result<BTreeMap<String, String>, ParseError> {
+        resolve(&self.foods).map_err(|unresolved| {
+            let (allergen, message) = match unresolved {
+                Unresolved::Impossible(allergen) => (allergen, "no ingredient is in every food with this allergen".to_string()),
+                Unresolved::Ambiguous(allergen, ingredients) => {
+                    let message = format!("allergen could be in any of {}", ingredients.join(", "));
+                    (allergen, message)
+                },
+            };
+            let (line, column) = self.first_seen[&allergen];
+            ParseError::new(line, column, &allergen, message)
+        })
+    }
+}
+
+/// The dangerous ingredients ordered by their allergen, separated by commas.
+pub fn canonical(dangerous : &BTreeMap<String, String>) -> String {
+    dangerous.values().cloned().collect::<Vec<String>>().join(",")
+}
+
+/// For each allergen, the ingredients found in every food listing it.
+pub fn candidates(foods : &[Food]) -> BTreeMap<&str, BTreeSet<&str>> {
+    let mut candidates : BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for food in foods {
+        let ingredients : BTreeSet<&str> = food.ingredients.iter().map(String::as_str).collect();
+        for allergen in &food.allergens {
+            candidates.entry(allergen)
+                .and_modify(|c| c.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+    candidates
+}
+
+/// Why the allergens can't each be given a single ingredient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unresolved {
+    Impossible(String),
+    Ambiguous(String, Vec<String>),
+}
+
+/// Settles allergens that have a single candidate left, removing that ingredient from the others,
+/// until every allergen has one.
+pub fn resolve(foods : &[Food]) -> Result<BTreeMap<String, String>, Unresolved> {
+    let mut candidates = candidates(foods);
+    let mut dangerous = BTreeMap::new();
+
+    while !candidates.is_empty() {
+        if let Some((allergen, _)) = candidates.iter().find(|(_, c)| c.is_empty()) {
+            return Err(Unresolved::Impossible(allergen.to_string()));
+        }
+        let (allergen, ingredient) = match candidates.iter().find(|(_, c)| c.len() == 1) {
+            Some((&allergen, c)) => (allergen, *c.iter().next().unwrap()),
+            None => {
+                let (allergen, c) = candidates.iter().next().unwrap();
+                return Err(Unresolved::Ambiguous(allergen.to_string(), c.iter().map(|i| i.to_string()).collect()));
+            },
+        };
+        candidates.remove(allergen);
+        candidates.values_mut().for_each(|c| { c.remove(ingredient); });
+        dangerous.insert(allergen.to_string(), ingredient.to_string());
+    }
+
+    Ok(dangerous)
+}
+
+pub fn read(input : &str) -> Result<Notes, ParseError> {
+    let mut foods = Vec::new();
+    let mut first_seen = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (ingredients, allergens) = match line.split_once(" (contains ") {
+            Some((ingredients, allergens)) => (ingredients, allergens.strip_suffix(')')
+                .ok_or_else(|| ParseError::at(i + 1, line, &line[line.len()..], "expected ')'"))?),
+            None => (line, ""),
+        };
+        let allergens : Vec<&str> = allergens.split(", ").filter(|a| !a.is_empty()).collect();
+        for allergen in &allergens {
+            let column = allergen.as_ptr() as usize - line.as_ptr() as usize + 1;
+            first_seen.entry(allergen.to_string()).or_insert((i + 1, column));
+        }
+        if let Some(unexpected) = ingredients.split_whitespace().find(|i| !i.chars().all(char::is_alphanumeric)) {
+            return Err(ParseError::at(i + 1, line, unexpected, "expected an ingredient"));
+        }
+        foods.push(Food {
+            ingredients: ingredients.split_whitespace().map(str::to_string).collect(),
+            allergens: allergens.iter().map(|a| a.to_string()).collect(),
+        });
+    }
+
+    Ok(Notes { foods, first_seen })
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Notes;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(notes : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(notes.safe_count().into())
+    }
+
+    fn part2(notes : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(notes.dangerous().map(|dangerous| canonical(&dangerous).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical, read};
+
+    #[test]
+    fn dangerous_ingredients() {
+        let notes = read("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)\n\
+                          trh fvjkl sbzzf mxmxvkd (contains dairy)\n\
+                          sqjhc fvjkl (contains soy)\n\
+                          sqjhc mxmxvkd sbzzf (contains fish)\n").unwrap();
+        assert_eq!(notes.safe_count(), 5);
+        let dangerous = notes.dangerous().unwrap();
+        assert_eq!(dangerous["soy"], "fvjkl");
+        assert_eq!(canonical(&dangerous), "mxmxvkd,sqjhc,fvjkl");
+    }
+
+    #[test]
+    fn unresolved() {
+        let notes = read("a b (contains dairy)\nb a c (contains dairy)\n").unwrap();
+        assert_eq!(notes.safe_count(), 1);
+        let err = notes.dangerous().unwrap_err();
+        assert_eq!(err.to_string(), "1:15: allergen could be in any of a, b (found 'dairy')");
+        let err = read("a (contains dairy, fish)\nb (contains fish)\n").unwrap().dangerous().unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 20, "fish"));
+    }
+}
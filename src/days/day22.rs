@@ -1,44 +1,42 @@
-#![feature(iter_map_while)]
-
 use std::collections::{HashSet, VecDeque};
-use std::str::Lines;
-use std::{env, io};
-use std::fs::File;
-use std::io::Read;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
-type Deck = VecDeque<u8>;
-type Decks = (Deck, Deck);
+use crate::error::{parse, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::tools::groups;
+
+pub type Deck = VecDeque<u8>;
+pub type Decks = (Deck, Deck);
 
 #[derive(Debug,PartialEq)]
-enum Player { One, Two }
+pub enum Player { One, Two }
+
+/// Reads a group of lines, as split by [`groups`], headed by the `player` line.
+pub fn read_deck(group : &[(usize, &str)], player : &str) -> Result<Deck, ParseError> {
+    match group.first() {
+        Some((_, l)) if *l == player => {},
+        Some(&(i, l)) => return Err(ParseError::at(i, l, l, format!("expected '{}'", player))),
+        None => return Err(ParseError::new(1, 1, "", format!("expected '{}'", player))),
+    }
 
-fn read_deck(lines : & mut Lines) -> Deck {
-    lines.map_while(|l| {
-        if l.is_empty() {
-            None
-        } else {
-            Some(l.parse::<u8>().unwrap())
-        }
-    })
+    group[1..]
+        .iter()
+        .map(|&(i, l)| parse::<u8>(i, l, l))
         .collect()
 }
 
-fn read(input : &str) -> Decks {
-    let mut decks : Decks = (Default::default(), Default::default());
+pub fn read(input : &str) -> Result<Decks, ParseError> {
+    let groups = groups(input);
 
-    let lines = & mut input.lines();
-
-    lines.next();
-    decks.0 = read_deck(lines);
-    lines.next();
-    decks.1 = read_deck(lines);
-
-    decks
+    match &groups[..] {
+        [deck0, deck1] => Ok((read_deck(deck0, "Player 1:")?, read_deck(deck1, "Player 2:")?)),
+        [.., extra] if groups.len() > 2 => Err(ParseError::at(extra[0].0, extra[0].1, extra[0].1, "expected only two decks")),
+        _ => Err(ParseError::new(input.lines().count() + 1, 1, "", "expected two decks")),
+    }
 }
 
-fn winner(decks : &Decks) -> (Player, u32, Deck) {
+pub fn winner(decks : &Decks) -> (Player, u32, Deck) {
     let mut result = if decks.0.is_empty() { (Player::Two, 0, decks.1.clone()) } else { (Player::One, 0, decks.0.clone()) };
     result.1 = result.2
         .iter()
@@ -48,7 +46,7 @@ fn winner(decks : &Decks) -> (Player, u32, Deck) {
     result
 }
 
-fn combat(mut decks : Decks) -> (Player, u32, Deck) {
+pub fn combat(mut decks : Decks) -> (Player, u32, Deck) {
     while !decks.0.is_empty() && !decks.1.is_empty() {
         let c0 = decks.0.pop_front().unwrap();
         let c1 = decks.1.pop_front().unwrap();
@@ -63,7 +61,7 @@ fn combat(mut decks : Decks) -> (Player, u32, Deck) {
     winner(&decks)
 }
 
-fn recursive_combat(mut decks : Decks) -> (Player, u32, Deck) {
+pub fn recursive_combat(mut decks : Decks) -> (Player, u32, Deck) {
     let mut seen : HashSet<u64> = HashSet::new();
     while !decks.0.is_empty() && !decks.1.is_empty() {
         let mut hasher = DefaultHasher::new();
@@ -104,28 +102,28 @@ fn recursive_combat(mut decks : Decks) -> (Player, u32, Deck) {
     winner(&decks)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        panic!("Provide input file");
-    }
+pub struct Day22;
 
-    let file = File::open(&args[1])
-        .expect("Couldn't read input file");
+impl Solution for Day22 {
+    type Input = Decks;
 
-    let mut input = String::new();
-    io::BufReader::new(file).read_to_string(&mut input).expect("Could not read file!");
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
 
-    let starting = read(&input);
+    fn part1(decks : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(combat(decks.clone()).1.into())
+    }
 
-    println!("Part 1: {}", combat(starting.clone()).1);
-    println!("Part 2: {}", recursive_combat(starting).1);
+    fn part2(decks : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(recursive_combat(decks.clone()).1.into()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{read, combat, Player, Decks, recursive_combat};
+    use super::{read, combat, Player, Decks, recursive_combat, Day22};
+    use crate::solution::{Answer, Solution};
 
     fn example_deck() -> Decks {
         read(r#"Player 1:
@@ -141,7 +139,7 @@ Player 2:
 4
 7
 10
-"#)
+"#).unwrap()
     }
 
     #[test]
@@ -161,4 +159,19 @@ Player 2:
         assert_eq!(res.0, Player::Two);
         assert_eq!(res.1, 291);
     }
+
+    #[test]
+    fn solution_answers() {
+        let decks = example_deck();
+        assert_eq!(Day22::part1(&decks), Ok(Answer::Number(306)));
+        assert_eq!(Day22::part2(&decks), Some(Ok(Answer::Number(291))));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = read("Player 1:\n9\n-2\n\nPlayer 2:\n5\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 1, "-2"));
+        let err = read("Player 1:\n9\n\nPlayer 3:\n5\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (4, 1, "Player 3:"));
+    }
 }
@@ -0,0 +1,160 @@
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+/// Cups in a circle, as the label of the cup clockwise of each label. Index 0 is unused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cups {
+    pub next : Vec<u32>,
+    pub current : u32,
+}
+
+impl Cups {
+    /// The cups labelled in `labels`, followed by the next labels up to `total` cups, the first being current.
+    /// None unless `labels` holds each of 1 to its length once and there are at least 4 cups, as a move
+    /// picks up three and needs a fourth to put them after.
+    pub fn new(labels : &[u32], total : usize) -> Option<Cups> {
+        let mut seen = vec![false; labels.len() + 1];
+        for &label in labels {
+            if label == 0 || label as usize > labels.len() || std::mem::replace(&mut seen[label as usize], true) {
+                return None;
+            }
+        }
+        let count = total.max(labels.len());
+        if count < 4 {
+            return None;
+        }
+        let count = count as u32;
+        let order = labels.iter().copied().chain(labels.len() as u32 + 1..=count);
+
+        let mut next = vec![0; count as usize + 1];
+        let first = labels.first().copied().unwrap_or(1);
+        let last = order.fold(first, |previous, label| {
+            next[previous as usize] = label;
+            label
+        });
+        next[last as usize] = first;
+
+        Some(Cups { next, current: first })
+    }
+
+    pub fn len(&self) -> usize {
+        self.next.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Picks up the three cups after the current one, puts them after the cup labelled one less
+    /// (skipping those picked up, wrapping to the highest) and moves on to the next cup.
+    pub fn step(&mut self) {
+        let max = self.len() as u32;
+        let first = self.next[self.current as usize];
+        let second = self.next[first as usize];
+        let third = self.next[second as usize];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 { max } else { destination - 1 };
+            if destination != first && destination != second && destination != third {
+                break;
+            }
+        }
+
+        self.next[self.current as usize] = self.next[third as usize];
+        self.next[third as usize] = self.next[destination as usize];
+        self.next[destination as usize] = first;
+        self.current = self.next[self.current as usize];
+    }
+
+    pub fn play(&mut self, moves : usize) {
+        for _ in 0..moves {
+            self.step();
+        }
+    }
+
+    /// The labels clockwise, starting at `label`.
+    pub fn from(&self, label : u32) -> impl Iterator<Item = u32> + '_ {
+        std::iter::successors(Some(label), move |&l| Some(self.next[l as usize])).take(self.len())
+    }
+}
+
+pub fn read(input : &str) -> Result<Vec<u32>, ParseError> {
+    let line = input.lines().next().unwrap_or("").trim();
+    let mut seen = vec![false; line.len() + 1];
+
+    line.char_indices()
+        .map(|(i, c)| {
+            let token = &line[i..i + c.len_utf8()];
+            let label = c.to_digit(10)
+                .filter(|&l| l >= 1 && (l as usize) <= line.len())
+                .ok_or_else(|| ParseError::at(1, line, token, format!("expected a label from 1 to {}", line.len())))?;
+            if std::mem::replace(&mut seen[label as usize], true) {
+                return Err(ParseError::at(1, line, token, "label used twice"));
+            }
+            Ok(label)
+        })
+        .collect::<Result<Vec<u32>, ParseError>>()
+        // A move picks up three cups and needs a fourth to put them after.
+        .and_then(|labels| if labels.len() < 4 { Err(ParseError::at(1, line, line, "expected at least 4 cup labels")) } else { Ok(labels) })
+}
+
+fn cups(labels : &[u32], total : usize) -> Result<Cups, ParseError> {
+    Cups::new(labels, total).ok_or_else(|| ParseError::new(1, 1, "", "expected at least 4 cups labelled 1 and up"))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<u32>;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read(input)
+    }
+
+    fn part1(labels : &Self::Input) -> Result<Answer, ParseError> {
+        let mut cups = cups(labels, labels.len())?;
+        cups.play(100);
+        Ok(cups.from(1).skip(1).map(|l| l.to_string()).collect::<String>().into())
+    }
+
+    fn part2(labels : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        Some(cups(labels, 1_000_000).map(|mut cups| {
+            cups.play(10_000_000);
+            cups.from(1).skip(1).take(2).map(u64::from).product::<u64>().into()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, Cups};
+
+    #[test]
+    fn moves() {
+        let mut cups = Cups::new(&read("389125467").unwrap(), 9).unwrap();
+        cups.step();
+        assert_eq!(cups.current, 2);
+        assert_eq!(cups.from(3).collect::<Vec<u32>>(), vec![3, 2, 8, 9, 1, 5, 4, 6, 7]);
+        cups.play(9);
+        assert_eq!(cups.from(5).collect::<Vec<u32>>(), vec![5, 8, 3, 7, 4, 1, 9, 2, 6]);
+        cups.play(90);
+        assert_eq!(cups.from(1).skip(1).collect::<Vec<u32>>(), vec![6, 7, 3, 8, 4, 5, 2, 9]);
+    }
+
+    #[test]
+    fn extends_labels() {
+        let cups = Cups::new(&[3, 1, 2], 5).unwrap();
+        assert_eq!(cups.from(3).collect::<Vec<u32>>(), vec![3, 1, 2, 4, 5]);
+        assert_eq!(Cups::new(&[], 0), None);
+        assert_eq!(Cups::new(&[1, 2, 3], 3), None);
+        assert_eq!(Cups::new(&[1, 3], 4), None);
+        assert_eq!(Cups::new(&[2, 2, 1], 4), None);
+        assert!(Cups::new(&[], 4).is_some());
+        assert_eq!(read("3121").unwrap_err().column, 4);
+        assert_eq!(read("31a").unwrap_err().token, "a");
+        assert_eq!(read("").unwrap_err().to_string(), "1:1: expected at least 4 cup labels");
+        assert_eq!(read("312").unwrap_err().to_string(), "1:1: expected at least 4 cup labels (found '312')");
+        assert!(read("3124").is_ok());
+    }
+}
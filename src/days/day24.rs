@@ -1,14 +1,14 @@
 use std::collections::HashMap;
-use std::{env, io};
-use std::fs::File;
-use std::io::Read;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
-enum Direction {
+pub enum Direction {
     East, NorthEast, NorthWest, West, SouthWest, SouthEast,
 }
 
-fn coord(d : Direction) -> (i32, i32) {
+pub fn coord(d : Direction) -> (i32, i32) {
     match d {
         Direction::East => (2, 0),
         Direction::NorthEast => (1, 1),
@@ -19,42 +19,42 @@ fn coord(d : Direction) -> (i32, i32) {
     }
 }
 
-type Instructions = Vec<Vec<Direction>>;
+pub type Instructions = Vec<Vec<Direction>>;
 
-fn read_directions(input : &str) -> Instructions {
-    input.lines().into_iter()
-        .map(|l| {
-            let mut it = l.chars().into_iter();
+pub fn read_directions(input : &str) -> Result<Instructions, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let mut it = l.char_indices();
             let mut directions : Vec<Direction> = Vec::new();
-            loop {
-                let c = match it.next() {
-                    Some(c) => c,
-                    None => break,
-                };
+            while let Some((pos, c)) = it.next() {
                 let dir = match c {
                     'e' => Direction::East,
                     'w' => Direction::West,
-                    _ => {
-                        match (c, it.next().expect("Expected another char after n or s")) {
-                            ('n', 'e') => Direction::NorthEast,
-                            ('n', 'w') => Direction::NorthWest,
-                            ('s', 'e') => Direction::SouthEast,
-                            ('s', 'w') => Direction::SouthWest,
-                            _ => panic!("Unexpected!"),
+                    'n' | 's' => {
+                        let next = it.next().map(|(_, c)| c);
+                        let token = l.get(pos..pos + 1 + next.map_or(0, char::len_utf8)).unwrap_or(&l[pos..]);
+                        match (c, next) {
+                            ('n', Some('e')) => Direction::NorthEast,
+                            ('n', Some('w')) => Direction::NorthWest,
+                            ('s', Some('e')) => Direction::SouthEast,
+                            ('s', Some('w')) => Direction::SouthWest,
+                            _ => return Err(ParseError::at(i + 1, l, token, "expected ne, nw, se or sw")),
                         }
-                    }
+                    },
+                    _ => return Err(ParseError::at(i + 1, l, &l[pos..pos + c.len_utf8()], "unknown direction")),
                 };
                 directions.push(dir);
             }
-            directions
+            Ok(directions)
         })
         .collect()
 }
 
-type Coord = (i32,i32);
-type Floor = HashMap<Coord, bool>;
+pub type Coord = (i32,i32);
+pub type Floor = HashMap<Coord, bool>;
 
-fn do_floor(ins : Instructions) -> Floor {
+pub fn do_floor(ins : &Instructions) -> Floor {
     let mut floor = Floor::default();
     ins.iter().map(|i| {
         i.iter().fold((0, 0), |c, &d| {
@@ -67,7 +67,7 @@ fn do_floor(ins : Instructions) -> Floor {
     floor
 }
 
-fn floor_evolve(mut floor : Floor) -> Floor {
+pub fn floor_evolve(mut floor : Floor) -> Floor {
     let mut neighbors : HashMap<Coord, i32> = HashMap::new();
 
     floor.iter().filter(|(_,&v)| v).for_each(|(&c,_)| {
@@ -93,39 +93,37 @@ fn floor_evolve(mut floor : Floor) -> Floor {
     floor
 }
 
-fn floor_count_black(floor : &Floor) -> usize {
+pub fn floor_count_black(floor : &Floor) -> usize {
     floor.iter().filter(|(_,&v)| v).count()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        panic!("Provide input file");
-    }
+pub struct Day24;
 
-    let file = File::open(&args[1])
-        .expect("Couldn't read input file");
+impl Solution for Day24 {
+    type Input = Instructions;
 
-    let mut input = String::new();
-    io::BufReader::new(file).read_to_string(&mut input).expect("Could not read file!");
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        read_directions(input)
+    }
 
-    let directions = read_directions(&input);
+    fn part1(directions : &Self::Input) -> Result<Answer, ParseError> {
+        Ok(floor_count_black(&do_floor(directions)).into())
+    }
 
-    let mut floor = do_floor(directions);
+    fn part2(directions : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        let mut floor = do_floor(directions);
 
-    println!("Part 1: {}", floor_count_black(&floor));
+        for _ in 0..100 {
+            floor = floor_evolve(floor);
+        }
 
-    for _ in 0..100 {
-        floor = floor_evolve(floor);
+        Some(Ok(floor_count_black(&floor).into()))
     }
-
-    println!("Part 2: {}", floor_count_black(&floor));
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{read_directions, Instructions, Direction, do_floor, floor_evolve, floor_count_black};
+    use super::{read_directions, Instructions, Direction, do_floor, floor_evolve, floor_count_black};
 
     fn example_input() -> Instructions {
     read_directions(r#"sesenwnenenewseeswwswswwnenewsewsw
@@ -147,7 +145,7 @@ wnwnesenesenenwwnenwsewesewsesesew
 nenewswnwewswnenesenwnesewesw
 eneswnwswnwsenenwnwnwwseeswneewsenese
 neswnwewnwnwseenwseesewsenwsweewe
-wseweeenwnesenwwwswnew"#)
+wseweeenwnesenwwwswnew"#).unwrap()
     }
 
     #[test]
@@ -158,13 +156,13 @@ wseweeenwnesenwwwswnew"#)
 
     #[test]
     fn do_example_floor() {
-        let floor = do_floor(example_input());
+        let floor = do_floor(&example_input());
         assert_eq!(floor_count_black(&floor), 10);
     }
 
     #[test]
     fn do_floor_evolve() {
-        let mut floor = do_floor(example_input());
+        let mut floor = do_floor(&example_input());
 
         floor = floor_evolve(floor);
 
@@ -176,4 +174,11 @@ wseweeenwnesenwwwswnew"#)
         assert_eq!(floor_count_black(&floor), 2208);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn malformed() {
+        let err = read_directions("esew\nesx\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str(), err.message.as_str()), (2, 2, "sx", "expected ne, nw, se or sw"));
+        let err = read_directions("nwe\nnwex\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str(), err.message.as_str()), (2, 4, "x", "unknown direction"));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::{parse_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub const MODULUS : u64 = 20201227;
+
+pub fn transform_step(subject : u64, mut value : u64) -> u64 {
+    value *= subject;
+    value % MODULUS
+}
+
+pub fn find_loop_size(public_key : u64) -> u64 {
+    let subject : u64 = 7;
+    let mut value : u64 = 1;
+    let mut loop_size : u64 = 0;
+    while value != public_key {
+        value = transform_step(subject, value);
+        loop_size += 1;
+    }
+    loop_size
+}
+
+pub fn transform_n(subject : u64, n : u64) -> u64 {
+    let mut value : u64 = 1;
+    for _ in 0..n {
+        value = transform_step(subject, value)
+    }
+    value
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (u64, u64);
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError> {
+        let keys : Vec<u64> = parse_lines(input)?;
+        // Only keys in 1..MODULUS are a power of 7, others would make find_loop_size loop forever.
+        if let Some((i, line)) = input.lines().enumerate().find(|&(i, _)| !(1..MODULUS).contains(&keys[i])) {
+            return Err(ParseError::at(i + 1, line, line.trim(), format!("expected a public key from 1 to {}", MODULUS - 1)));
+        }
+        match keys[..] {
+            [card_public_key, door_public_key] => Ok((card_public_key, door_public_key)),
+            ref keys => Err(ParseError::new(keys.len() + 1, 1, "", "expected the card and door public key")),
+        }
+    }
+
+    fn part1(&(card_public_key, door_public_key) : &Self::Input) -> Result<Answer, ParseError> {
+        let door_loop_size = find_loop_size(door_public_key);
+        Ok(transform_n(card_public_key, door_loop_size).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_loop_size, transform_n, Day25};
+    use crate::solution::Solution;
+
+    #[test]
+    fn part1_encryption_key() {
+        let card_public_key =  5_764_801;
+        let door_public_key = 17_807_724;
+
+        let card_loop_size = find_loop_size(card_public_key);
+        assert_eq!(card_loop_size, 8);
+        let door_loop_size = find_loop_size(door_public_key);
+        assert_eq!(door_loop_size, 11);
+
+        let encryption_key = transform_n(card_public_key, door_loop_size);
+        assert_eq!(encryption_key, transform_n(door_public_key, card_loop_size));
+        assert_eq!(encryption_key, 14_897_079)
+    }
+
+    #[test]
+    fn rejects_keys_out_of_range() {
+        assert!(Day25::parse("5764801\n17807724\n").is_ok());
+        let err = Day25::parse("5764801\n0\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:1: expected a public key from 1 to 20201226 (found '0')");
+        let err = Day25::parse(" 20201227\n17807724\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:2: expected a public key from 1 to 20201226 (found '20201227')");
+    }
+}
\ No newline at end of file
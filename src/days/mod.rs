@@ -0,0 +1,73 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::error::ParseError;
+use crate::solution::{solve, Part, Report};
+use crate::tools::Source;
+
+pub struct Day {
+    pub number : u8,
+    pub title : &'static str,
+    pub solve : fn(&str, Option<Part>) -> Result<Report, ParseError>,
+}
+
+impl Day {
+    pub fn default_input(&self) -> Source {
+        Source::from_arg(&format!("input/day{:02}", self.number))
+    }
+}
+
+pub const DAYS : &[Day] = &[
+    Day { number: 1, title: "Report Repair", solve: solve::<day01::Day01> },
+    Day { number: 2, title: "Password Philosophy", solve: solve::<day02::Day02> },
+    Day { number: 3, title: "Toboggan Trajectory", solve: solve::<day03::Day03> },
+    Day { number: 4, title: "Passport Processing", solve: solve::<day04::Day04> },
+    Day { number: 5, title: "Binary Boarding", solve: solve::<day05::Day05> },
+    Day { number: 6, title: "Custom Customs", solve: solve::<day06::Day06> },
+    Day { number: 7, title: "Handy Haversacks", solve: solve::<day07::Day07> },
+    Day { number: 8, title: "Handheld Halting", solve: solve::<day08::Day08> },
+    Day { number: 9, title: "Encoding Error", solve: solve::<day09::Day09> },
+    Day { number: 10, title: "Adapter Array", solve: solve::<day10::Day10> },
+    Day { number: 11, title: "Seating System", solve: solve::<day11::Day11> },
+    Day { number: 12, title: "Rain Risk", solve: solve::<day12::Day12> },
+    Day { number: 13, title: "Shuttle Search", solve: solve::<day13::Day13> },
+    Day { number: 14, title: "Docking Data", solve: solve::<day14::Day14> },
+    Day { number: 15, title: "Rambunctious Recitation", solve: solve::<day15::Day15> },
+    Day { number: 16, title: "Ticket Translation", solve: solve::<day16::Day16> },
+    Day { number: 17, title: "Conway Cubes", solve: solve::<day17::Day17> },
+    Day { number: 18, title: "Operation Order", solve: solve::<day18::Day18> },
+    Day { number: 19, title: "Monster Messages", solve: solve::<day19::Day19> },
+    Day { number: 20, title: "Jurassic Jigsaw", solve: solve::<day20::Day20> },
+    Day { number: 21, title: "Allergen Assessment", solve: solve::<day21::Day21> },
+    Day { number: 22, title: "Crab Combat", solve: solve::<day22::Day22> },
+    Day { number: 23, title: "Crab Cups", solve: solve::<day23::Day23> },
+    Day { number: 24, title: "Lobby Layout", solve: solve::<day24::Day24> },
+    Day { number: 25, title: "Combo Breaker", solve: solve::<day25::Day25> },
+];
+
+pub fn find(number : u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
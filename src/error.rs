@@ -0,0 +1,78 @@
+use std::{any, error, fmt};
+use std::str::FromStr;
+
+/// Where and why an input could not be parsed, or solved once parsed. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file : Option<String>,
+    pub line : usize,
+    pub column : usize,
+    pub token : String,
+    pub message : String,
+}
+
+impl ParseError {
+    pub fn new(line : usize, column : usize, token : &str, message : impl Into<String>) -> Self {
+        ParseError { file: None, line, column, token: token.to_string(), message: message.into() }
+    }
+
+    /// Error for `token`, a slice of the text of line `line_no`, so the column follows from where it sits.
+    pub fn at(line_no : usize, line : &str, token : &str, message : impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() { offset + 1 } else { 1 };
+        ParseError::new(line_no, column, token, message)
+    }
+
+    pub fn in_file(mut self, file : &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " (found '{}')", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses `token`, a slice of line `line_no`, pointing at it when that fails.
+pub fn parse<T : FromStr>(line_no : usize, line : &str, token : &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(line_no, line, token, format!("expected {}", any::type_name::<T>())))
+}
+
+/// Parses one number per line.
+pub fn parse_lines<T : FromStr>(input : &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse(i + 1, l, l.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_lines, ParseError};
+
+    #[test]
+    fn points_at_token() {
+        let line = "mem[8] = 1x";
+        let err = parse::<u64>(3, line, &line[9..]).unwrap_err();
+        assert_eq!(err, ParseError::new(3, 10, "1x", "expected u64"));
+        assert_eq!(err.in_file("input/day14").to_string(), "input/day14:3:10: expected u64 (found '1x')");
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        assert_eq!(parse_lines::<u32>("1\n2\n").unwrap(), vec![1, 2]);
+        assert_eq!(parse_lines::<u32>("1\n-2\n").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,14 @@
+//! Advent of Code 2020 solvers.
+//!
+//! Every puzzle lives in its own module under [`days`], e.g. `days::day08::execute`
+//! or `days::day14::VM`, implements [`solution::Solution`] and is registered in
+//! [`days::DAYS`] for the `aoc` runner.
+
+pub mod bench;
+pub mod check;
+pub mod days;
+pub mod error;
+pub mod output;
+pub mod pool;
+pub mod solution;
+pub mod tools;
@@ -0,0 +1,515 @@
+use std::{env, process};
+
+use aoc::{bench, check, days, output, pool};
+use aoc::output::DayOutput;
+use aoc::days::{day01, day02, day20, Day, DAYS};
+use aoc::solution::Part;
+use aoc::tools::Source;
+
+const USAGE : &str = "Usage:
+    aoc list
+    aoc run <day|all> [--part <1|2>] [--jobs <n>] [--format <text|json>] [--check] [--answers <file>] [input]
+    aoc bench <day|all> [--runs <n>] [--save <file>] [--baseline <file>] [--threshold <percent>] [input]
+    aoc image [--pattern <file>] [input]
+    aoc passwords [--format <text|csv|json>] [--failed] [input]
+    aoc sums [--k <n>] [--target <n>] [input]
+
+The input is a file, or - for standard input. --jobs solves the days, and their
+parts, on that many threads (1 by default). With --check the answers are
+compared against the expected answers, read from answers.toml by default.
+
+bench times parse, part 1 and part 2 over a number of runs (5 by default). A
+baseline written with --save can be compared against with --baseline; a median
+more than --threshold percent (10 by default) slower counts as a regression.
+
+image assembles the Day 20 tiles and prints the image, turned to show the most
+sea monsters, or the pattern drawn with # in --pattern, marked with O.
+
+passwords checks every Day 2 entry against each password policy and gives the
+reason it fails; --failed only lists entries that break a policy.
+
+sums lists every set of --k (2 by default) distinct Day 1 entries that add up
+to --target (2020 by default), with their product.";
+
+struct RunArgs {
+    days : Vec<&'static Day>,
+    part : Option<Part>,
+    input : Option<String>,
+    check : bool,
+    answers : String,
+    json : bool,
+    jobs : usize,
+}
+
+struct BenchArgs {
+    days : Vec<&'static Day>,
+    input : Option<String>,
+    runs : usize,
+    save : Option<String>,
+    baseline : Option<String>,
+    threshold : f64,
+}
+
+fn parse_days(arg : Option<&String>) -> Result<Vec<&'static Day>, String> {
+    match arg.map(String::as_str) {
+        Some("all") => Ok(DAYS.iter().collect()),
+        Some(day) => {
+            let number : u8 = day.parse().map_err(|_| format!("Invalid day '{}'", day))?;
+            Ok(vec![days::find(number).ok_or(format!("Day {} is not implemented", number))?])
+        },
+        None => Err("Provide a day or 'all'".to_string()),
+    }
+}
+
+fn parse_run_args(args : &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter();
+
+    let days = parse_days(args.next())?;
+
+    let mut part = None;
+    let mut input = None;
+    let mut check = false;
+    let mut answers = "answers.toml".to_string();
+    let mut json = false;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = match args.next().map(String::as_str) {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    _ => return Err("--part must be 1 or 2".to_string()),
+                }
+            },
+            "--check" => check = true,
+            "--jobs" => jobs = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0)
+                .ok_or("--jobs requires a positive number")?,
+            "--format" => {
+                json = match args.next().map(String::as_str) {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => return Err("--format must be text or json".to_string()),
+                }
+            },
+            "--answers" => {
+                answers = args.next().ok_or("--answers requires a file")?.to_string();
+                check = true;
+            },
+            path if input.is_none() => input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    if input.is_some() && days.len() > 1 {
+        return Err("An input file can only be given for a single day".to_string());
+    }
+
+    Ok(RunArgs { days, part, input, check, answers, json, jobs })
+}
+
+fn parse_bench_args(args : &[String]) -> Result<BenchArgs, String> {
+    let mut args = args.iter();
+
+    let mut bench = BenchArgs {
+        days: parse_days(args.next())?,
+        input: None,
+        runs: 5,
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => bench.runs = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0)
+                .ok_or("--runs requires a positive number")?,
+            "--save" => bench.save = Some(args.next().ok_or("--save requires a file")?.to_string()),
+            "--baseline" => bench.baseline = Some(args.next().ok_or("--baseline requires a file")?.to_string()),
+            "--threshold" => bench.threshold = args.next().and_then(|n| n.parse().ok())
+                .ok_or("--threshold requires a percentage")?,
+            path if bench.input.is_none() => bench.input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    if bench.input.is_some() && bench.days.len() > 1 {
+        return Err("An input file can only be given for a single day".to_string());
+    }
+
+    Ok(bench)
+}
+
+struct ImageArgs {
+    pattern : Option<String>,
+    input : Option<String>,
+}
+
+fn parse_image_args(args : &[String]) -> Result<ImageArgs, String> {
+    let mut args = args.iter();
+    let mut image = ImageArgs { pattern: None, input: None };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pattern" => image.pattern = Some(args.next().ok_or("--pattern requires a file")?.to_string()),
+            path if image.input.is_none() => image.input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    Ok(image)
+}
+
+enum ReportFormat { Text, Csv, Json }
+
+struct PasswordArgs {
+    format : ReportFormat,
+    failed : bool,
+    input : Option<String>,
+}
+
+fn parse_password_args(args : &[String]) -> Result<PasswordArgs, String> {
+    let mut args = args.iter();
+    let mut passwords = PasswordArgs { format: ReportFormat::Text, failed: false, input: None };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                passwords.format = match args.next().map(String::as_str) {
+                    Some("text") => ReportFormat::Text,
+                    Some("csv") => ReportFormat::Csv,
+                    Some("json") => ReportFormat::Json,
+                    _ => return Err("--format must be text, csv or json".to_string()),
+                }
+            },
+            "--failed" => passwords.failed = true,
+            path if passwords.input.is_none() => passwords.input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    Ok(passwords)
+}
+
+struct SumArgs {
+    k : usize,
+    target : i64,
+    input : Option<String>,
+}
+
+fn parse_sum_args(args : &[String]) -> Result<SumArgs, String> {
+    let mut args = args.iter();
+    let mut sums = SumArgs { k: 2, target: day01::TARGET, input: None };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--k" => sums.k = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0)
+                .ok_or("--k requires a positive number")?,
+            "--target" => sums.target = args.next().and_then(|n| n.parse().ok())
+                .ok_or("--target requires a number")?,
+            path if sums.input.is_none() => sums.input = Some(path.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    Ok(sums)
+}
+
+fn exit_with(e : impl std::fmt::Display) -> ! {
+    eprintln!("{}", e);
+    process::exit(1);
+}
+
+fn bench_days(args : &BenchArgs) -> bool {
+    let baseline = match &args.baseline {
+        Some(path) => bench::load(path).unwrap_or_else(|e| exit_with(e)),
+        None => Vec::new(),
+    };
+
+    let mut measurements = Vec::new();
+    let mut regressions = 0;
+
+    for day in &args.days {
+        let source = source_for(day, &args.input);
+        let name = source.to_string();
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {:02}: skipped, couldn't read {}: {}", day.number, name, e);
+                continue;
+            }
+        };
+
+        let day_measurements = match bench::bench(day, &input, args.runs) {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.number, e.in_file(&name));
+                continue;
+            }
+        };
+
+        for m in day_measurements {
+            match m.change(&baseline) {
+                Some((base, change)) => {
+                    let regressed = m.regressed(&baseline, args.threshold / 100.0);
+                    println!("{}  baseline {:>10.2?} ({:+.1}%){}", m, base, change * 100.0, if regressed { " REGRESSION" } else { "" });
+                    if regressed {
+                        regressions += 1;
+                    }
+                },
+                None => println!("{}", m),
+            }
+            measurements.push(m);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        bench::save(path, &measurements).unwrap_or_else(|e| exit_with(e));
+    }
+
+    if args.baseline.is_some() {
+        println!("{} regressions", regressions);
+    }
+
+    regressions == 0
+}
+
+fn show_image(args : &ImageArgs) {
+    let read = |source : Source| {
+        let name = source.to_string();
+        source.read().map(|input| (name.clone(), input)).unwrap_or_else(|e| exit_with(format!("couldn't read {}: {}", name, e)))
+    };
+
+    let pattern = match &args.pattern {
+        Some(path) => {
+            let (name, pattern) = read(Source::from_arg(path));
+            day20::Pattern::read(&pattern).unwrap_or_else(|e| exit_with(e.in_file(&name)))
+        },
+        None => day20::Pattern::read(day20::SEA_MONSTER).expect("The sea monster is a valid pattern"),
+    };
+
+    let (name, input) = read(source_for(days::find(20).expect("Day 20 is implemented"), &args.input));
+    let tiles = day20::read(&input).unwrap_or_else(|e| exit_with(e.in_file(&name)));
+    let assembly = day20::assembled(&tiles).unwrap_or_else(|e| exit_with(e.in_file(&name)));
+    let search = day20::search(&assembly.image(), &pattern);
+
+    print!("{}", search);
+    println!("{} found, roughness {}", search.found.len(), search.roughness());
+}
+
+fn report_passwords(args : &PasswordArgs) {
+    let source = source_for(days::find(2).expect("Day 2 is implemented"), &args.input);
+    let name = source.to_string();
+    let input = source.read().unwrap_or_else(|e| exit_with(format!("couldn't read {}: {}", name, e)));
+    let entries = day02::read(&input).unwrap_or_else(|e| exit_with(e.in_file(&name)));
+
+    let mut reports = day02::report(&entries, day02::POLICIES);
+    if args.failed {
+        reports.retain(|r| !r.compliant());
+    }
+
+    match args.format {
+        ReportFormat::Text => for r in &reports {
+            let verdicts : Vec<String> = r.verdicts.iter()
+                .map(|v| format!("{}: {}", v.policy, v.reason.as_deref().unwrap_or("ok")))
+                .collect();
+            println!("{}:{}: {}-{} {}: {}  {}", name, r.line, r.entry.lo, r.entry.hi, r.entry.ch, r.entry.password, verdicts.join("; "));
+        },
+        ReportFormat::Csv => print!("{}", day02::csv(&reports)),
+        ReportFormat::Json => println!("{}", day02::json(&reports)),
+    }
+}
+
+fn list_sums(args : &SumArgs) {
+    let source = source_for(days::find(1).expect("Day 1 is implemented"), &args.input);
+    let name = source.to_string();
+    let input = source.read().unwrap_or_else(|e| exit_with(format!("couldn't read {}: {}", name, e)));
+    let nums = aoc::error::parse_lines::<i64>(&input).unwrap_or_else(|e| exit_with(e.in_file(&name)));
+
+    let combinations = day01::k_sum(&nums, args.k, args.target);
+    for combination in &combinations {
+        let lines : Vec<String> = combination.iter().map(|i| (i + 1).to_string()).collect();
+        let terms : Vec<String> = combination.iter().map(|&i| nums[i].to_string()).collect();
+        let product = combination.iter().try_fold(1i128, |product, &i| product.checked_mul(nums[i] as i128))
+            .map_or_else(|| "too large for an i128".to_string(), |product| product.to_string());
+        println!("lines {}: {} = {}, product {}", lines.join(", "), terms.join(" + "), args.target, product);
+    }
+    println!("{} found", combinations.len());
+}
+
+fn source_for(day : &Day, input : &Option<String>) -> Source {
+    match input {
+        Some(arg) => Source::from_arg(arg),
+        None => day.default_input(),
+    }
+}
+
+/// Solves every part of every day as a separate task on `run.jobs` threads, then
+/// puts the answers back together in day order.
+fn run_parallel(run : &RunArgs) -> Vec<DayOutput> {
+    let inputs : Vec<(String, Result<String, String>)> = run.days.iter()
+        .map(|day| {
+            let source = source_for(day, &run.input);
+            let name = source.to_string();
+            let input = source.read().map_err(|e| format!("couldn't read {}: {}", name, e));
+            (name, input)
+        })
+        .collect();
+
+    let tasks : Vec<(usize, Part)> = inputs.iter()
+        .enumerate()
+        .filter(|(_, (_, input))| input.is_ok())
+        .flat_map(|(i, _)| [Part::One, Part::Two].iter().map(move |&part| (i, part)))
+        .filter(|&(_, part)| run.part.is_none_or(|p| p == part))
+        .collect();
+
+    let results = pool::map(&tasks, run.jobs, |&(i, part)| {
+        let input = inputs[i].1.as_ref().expect("Only readable inputs are solved");
+        (run.days[i].solve)(input, Some(part))
+    });
+
+    inputs.into_iter()
+        .enumerate()
+        .map(|(i, (name, input))| {
+            if let (Err(e), None) = (&input, &run.input) {
+                return DayOutput::skipped(run.days[i], &name, e.clone());
+            }
+            let mut reports = tasks.iter()
+                .zip(&results)
+                .filter(|((day, _), _)| *day == i)
+                .map(|(_, result)| result.clone().map_err(|e| e.in_file(&name).to_string()));
+            let result = input.and_then(|_| {
+                let mut merged = reports.next().expect("Every readable day has a task")?;
+                for report in reports {
+                    merged.parts.extend(report?.parts);
+                }
+                Ok(merged)
+            });
+            DayOutput::new(run.days[i], &name, result)
+        })
+        .collect()
+}
+
+/// Solves `day` for the input given on the command line, or skips it when its default input can't be read.
+fn run_day(day : &Day, part : Option<Part>, input : &Option<String>) -> DayOutput {
+    let source = source_for(day, input);
+    let name = source.to_string();
+    let result = source.read().map_err(|e| format!("couldn't read {}: {}", name, e));
+    if let (Err(e), None) = (&result, input) {
+        return DayOutput::skipped(day, &name, e.clone());
+    }
+    let result = result.and_then(|input| (day.solve)(&input, part).map_err(|e| e.in_file(&name).to_string()));
+
+    DayOutput::new(day, &name, result)
+}
+
+fn print_text(output : &DayOutput) {
+    for part in &output.parts {
+        match (&part.answer, &part.error) {
+            (Some(answer), _) => println!("Day {:02} part {}: {}", output.day, part.part, answer),
+            (None, e) => eprintln!("Day {:02} part {}: {}", output.day, part.part, e.as_deref().unwrap_or("no answer")),
+        }
+    }
+    if let Some(e) = &output.error {
+        eprintln!("Day {:02}: {}", output.day, e);
+    }
+    if let Some(reason) = &output.skipped {
+        println!("Day {:02}: skipped, {}", output.day, reason);
+    }
+}
+
+fn check_days(run : &RunArgs) -> bool {
+    let expected = check::load(&run.answers).unwrap_or_else(|e| exit_with(e));
+
+    let (mut passed, mut failed) = (0, 0);
+
+    for day in &run.days {
+        let entries : Vec<&check::Expected> = expected.iter()
+            .filter(|e| e.day == day.number && run.input.as_ref().is_none_or(|i| &e.input == i))
+            .collect();
+
+        if entries.is_empty() {
+            println!("Day {:02}: no expected answers", day.number);
+        }
+
+        for entry in entries {
+            for result in check::check(entry, run.part) {
+                println!("{}", result);
+                if result.verdict == check::Verdict::Pass {
+                    passed += 1;
+                } else {
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    failed == 0
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            for day in DAYS {
+                println!("{:02} {}", day.number, day.title);
+            }
+        },
+        Some("run") => {
+            let run = parse_run_args(&args[1..]).unwrap_or_else(|e| exit_with(format!("{}\n\n{}", e, USAGE)));
+            if run.check {
+                if !check_days(&run) {
+                    process::exit(1);
+                }
+                return;
+            }
+            let mut outputs = Vec::new();
+            let mut failed = false;
+            let mut emit = |output : DayOutput| {
+                failed |= output.failed();
+                if run.json {
+                    outputs.push(output);
+                } else {
+                    print_text(&output);
+                }
+            };
+            if run.jobs > 1 {
+                run_parallel(&run).into_iter().for_each(&mut emit);
+            } else {
+                for day in &run.days {
+                    emit(run_day(day, run.part, &run.input));
+                }
+            }
+            if run.json {
+                println!("{}", output::json(&outputs));
+            }
+            if failed {
+                process::exit(1);
+            }
+        },
+        Some("bench") => {
+            let args = parse_bench_args(&args[1..]).unwrap_or_else(|e| exit_with(format!("{}\n\n{}", e, USAGE)));
+            if !bench_days(&args) {
+                process::exit(1);
+            }
+        },
+        Some("image") => {
+            let args = parse_image_args(&args[1..]).unwrap_or_else(|e| exit_with(format!("{}\n\n{}", e, USAGE)));
+            show_image(&args);
+        },
+        Some("passwords") => {
+            let args = parse_password_args(&args[1..]).unwrap_or_else(|e| exit_with(format!("{}\n\n{}", e, USAGE)));
+            report_passwords(&args);
+        },
+        Some("sums") => {
+            let args = parse_sum_args(&args[1..]).unwrap_or_else(|e| exit_with(format!("{}\n\n{}", e, USAGE)));
+            list_sums(&args);
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
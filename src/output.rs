@@ -0,0 +1,127 @@
+use serde::{Serialize, Serializer};
+
+use crate::days::Day;
+use crate::solution::{Answer, Details, Part, Report};
+
+fn details_map<S : Serializer>(details : &Details, serializer : S) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(details.iter().map(|(k, v)| (k, v)))
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartOutput {
+    pub part : Part,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer : Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error : Option<String>,
+    #[serde(serialize_with = "details_map", skip_serializing_if = "Vec::is_empty")]
+    pub details : Details,
+    pub time_ns : u64,
+}
+
+/// Everything one day produced for an input, in the shape of the JSON output.
+#[derive(Debug, Serialize)]
+pub struct DayOutput {
+    pub day : u8,
+    pub title : &'static str,
+    pub input : String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns : Option<u64>,
+    pub parts : Vec<PartOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error : Option<String>,
+    /// Why the day wasn't run, like a missing default input. Unlike an error, this isn't a failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped : Option<String>,
+}
+
+impl DayOutput {
+    pub fn new(day : &Day, input : &str, result : Result<Report, String>) -> Self {
+        let mut output = DayOutput {
+            day: day.number,
+            title: day.title,
+            input: input.to_string(),
+            parse_ns: None,
+            parts: Vec::new(),
+            error: None,
+            skipped: None,
+        };
+
+        match result {
+            Ok(report) => {
+                output.parse_ns = Some(report.parse.as_nanos() as u64);
+                output.parts = report.parts
+                    .into_iter()
+                    .map(|s| {
+                        let (answer, error) = match s.answer {
+                            Ok(answer) => (Some(answer), None),
+                            Err(e) => (None, Some(e.in_file(input).to_string())),
+                        };
+                        PartOutput { part: s.part, answer, error, details: s.details, time_ns: s.time.as_nanos() as u64 }
+                    })
+                    .collect();
+            },
+            Err(e) => output.error = Some(e),
+        }
+
+        output
+    }
+
+    pub fn skipped(day : &Day, input : &str, reason : String) -> Self {
+        DayOutput {
+            day: day.number,
+            title: day.title,
+            input: input.to_string(),
+            parse_ns: None,
+            parts: Vec::new(),
+            error: None,
+            skipped: Some(reason),
+        }
+    }
+
+    /// Whether the day or any of its parts ended in an error.
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.parts.iter().any(|p| p.error.is_some())
+    }
+}
+
+pub fn json(outputs : &[DayOutput]) -> String {
+    serde_json::to_string_pretty(outputs).expect("Answers always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json, DayOutput};
+    use crate::days;
+
+    #[test]
+    fn day13_json() {
+        let day = days::find(13).unwrap();
+        let report = (day.solve)("939\n7,13,x,x,59,x,31,19\n", None).unwrap();
+        let output = DayOutput::new(day, "example", Ok(report));
+
+        let value : serde_json::Value = serde_json::from_str(&json(&[output])).unwrap();
+        let part1 = &value[0]["parts"][0];
+        assert_eq!(value[0]["day"], 13);
+        assert_eq!(part1["part"], 1);
+        assert_eq!(part1["answer"], 295);
+        assert_eq!(part1["details"]["bus"], 59);
+        assert_eq!(part1["details"]["wait"], 5);
+        assert!(value[0]["parts"][1].get("details").is_none());
+        assert!(value[0].get("error").is_none());
+    }
+
+    #[test]
+    fn failures() {
+        let day = days::find(16).unwrap();
+        let input = "a: 1-5\nb: 1-5\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4\n";
+        let output = DayOutput::new(day, "example", Ok((day.solve)(input, None).unwrap()));
+        assert!(output.error.is_none());
+        assert!(output.failed());
+
+        let day = days::find(13).unwrap();
+        assert!(!DayOutput::new(day, "example", Ok((day.solve)("939\n7,13,x,x,59,x,31,19\n", None).unwrap())).failed());
+        assert!(DayOutput::new(day, "example", Err("couldn't read example".to_string())).failed());
+        assert!(!DayOutput::skipped(day, "input/day13", "couldn't read input/day13".to_string()).failed());
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Runs `f` over every task on `jobs` worker threads and returns the results in task order.
+pub fn map<T : Sync, R : Send>(tasks : &[T], jobs : usize, f : impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results : Mutex<Vec<Option<R>>> = Mutex::new(tasks.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= tasks.len() {
+                    break;
+                }
+                let result = f(&tasks[i]);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("Every task ran"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::thread;
+    use super::map;
+
+    #[test]
+    fn keeps_task_order() {
+        let tasks : Vec<u64> = (0..20).collect();
+        let squares = map(&tasks, 4, |&n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, tasks.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+}
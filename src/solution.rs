@@ -0,0 +1,143 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::error::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part { One, Two }
+
+impl Serialize for Part {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(if *self == Part::One { 1 } else { 2 })
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Numbers too large for an i64 are kept as text rather than wrapped.
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n : $t) -> Self { i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number) }
+        })*
+    };
+}
+
+answer_from_number!(i32, i64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s : String) -> Self { Answer::Text(s) }
+}
+
+/// Named values found on the way to an answer, like the bus taken on Day 13.
+pub type Details = Vec<(&'static str, Answer)>;
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub part : Part,
+    /// The answer, or why the parsed input has none, like an ambiguous Day 16 ticket.
+    pub answer : Result<Answer, ParseError>,
+    pub details : Details,
+    pub time : Duration,
+}
+
+/// The answers to one run over an input, along with how long each stage took.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse : Duration,
+    pub parts : Vec<Solved>,
+}
+
+/// A puzzle, split in a parse stage and the two parts working on the parsed input.
+pub trait Solution {
+    type Input;
+
+    fn parse(input : &str) -> Result<Self::Input, ParseError>;
+
+    /// The answer, or an error pointing at what in the input stands in the way of one.
+    fn part1(input : &Self::Input) -> Result<Answer, ParseError>;
+
+    /// Not every day has a second part, Day 25 for instance only has one puzzle.
+    fn part2(_input : &Self::Input) -> Option<Result<Answer, ParseError>> {
+        None
+    }
+
+    /// Part 1 along with the details of how it was found, none unless a day has something to add.
+    fn part1_details(input : &Self::Input) -> Result<(Answer, Details), ParseError> {
+        Self::part1(input).map(|answer| (answer, Details::new()))
+    }
+
+    fn part2_details(input : &Self::Input) -> Option<Result<(Answer, Details), ParseError>> {
+        Self::part2(input).map(|answer| answer.map(|answer| (answer, Details::new())))
+    }
+}
+
+fn split(result : Result<(Answer, Details), ParseError>) -> (Result<Answer, ParseError>, Details) {
+    match result {
+        Ok((answer, details)) => (Ok(answer), details),
+        Err(e) => (Err(e), Details::new()),
+    }
+}
+
+/// Parses the input once and solves the requested part, or both when `part` is `None`.
+pub fn solve<S : Solution>(input : &str, part : Option<Part>) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let mut parts = Vec::new();
+
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let (answer, details) = split(S::part1_details(&input));
+        parts.push(Solved { part: Part::One, answer, details, time: start.elapsed() });
+    }
+
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        if let Some((answer, details)) = S::part2_details(&input).map(split) {
+            parts.push(Solved { part: Part::Two, answer, details, time: start.elapsed() });
+        }
+    }
+
+    Ok(Report { parse, parts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn large_numbers() {
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+        assert_eq!(Answer::from(-3i32), Answer::Number(-3));
+    }
+}
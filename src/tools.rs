@@ -1,25 +1,90 @@
-use std::{env,io::{self,BufRead},fs::File};
-use std::io::{BufReader, Lines};
+use std::{fmt, fs::File, io::{self, BufRead, BufReader, Read}};
+use std::path::PathBuf;
 
-pub fn input_reader() -> Option<Lines<BufReader<File>>> {
-    let args: Vec<String> = env::args().collect();
+/// Where a puzzle input comes from: a file, standard input or any buffered reader.
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Reader(Box<dyn BufRead + Send>),
+}
+
+impl Source {
+    /// Interprets a command line argument, `-` meaning standard input.
+    pub fn from_arg(arg : &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
 
-    if args.len() != 2 {
-        println!("Provide day01 file");
-        return None
+    pub fn reader(reader : impl BufRead + Send + 'static) -> Self {
+        Source::Reader(Box::new(reader))
     }
 
-    let file = File::open(&args[1])
-        .expect("Couldn't read day01");
+    pub fn read(self) -> io::Result<String> {
+        let mut input = String::new();
+        match self {
+            Source::Path(path) => BufReader::new(File::open(path)?).read_to_string(&mut input)?,
+            Source::Stdin => io::stdin().lock().read_to_string(&mut input)?,
+            Source::Reader(mut reader) => reader.read_to_string(&mut input)?,
+        };
+        Ok(input)
+    }
+}
 
-    Some(io::BufReader::new(file).lines())
+impl From<&str> for Source {
+    fn from(input : &str) -> Self {
+        Source::reader(io::Cursor::new(input.to_string()))
+    }
+}
+
+/// Splits the input in groups of lines separated by blank lines, keeping each line's number.
+pub fn groups(input : &str) -> Vec<Vec<(usize, &str)>> {
+    let mut groups = vec![Vec::new()];
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push((i + 1, line));
+        }
+    }
+
+    groups.retain(|g| !g.is_empty());
+    groups
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Reader(_) => write!(f, "<reader>"),
+        }
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
+    use super::{groups, Source};
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn sources() {
+        assert_eq!(Source::from("939\n7,13,x\n").read().unwrap(), "939\n7,13,x\n");
+        assert!(matches!(Source::from_arg("-"), Source::Stdin));
+        assert_eq!(Source::from_arg("input/day13_example").read().unwrap(), "939\n7,13,x,x,59,x,31,19\n");
+        assert!(Source::from_arg("input/day00").read().is_err());
+    }
+
+    #[test]
+    fn blank_line_groups() {
+        assert_eq!(groups("a\nb\n\n\nc\n\n"), vec![vec![(1, "a"), (2, "b")], vec![(5, "c")]]);
+        assert!(groups("").is_empty());
+    }
 }
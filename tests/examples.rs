@@ -0,0 +1,16 @@
+use aoc::check::{check, examples, Verdict};
+
+#[test]
+fn examples_match_expected_answers() {
+    let examples = examples("input").unwrap();
+    assert!(!examples.is_empty());
+
+    let failures : Vec<String> = examples
+        .iter()
+        .flat_map(|example| check(example, None))
+        .filter(|c| c.verdict != Verdict::Pass)
+        .map(|c| c.to_string())
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}